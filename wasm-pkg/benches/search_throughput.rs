@@ -0,0 +1,69 @@
+/// 統合シード探索のスループットベンチマーク
+/// 1コア（スカラー版・SIMD版）と全コア（マルチスレッド版）のハッシュ/秒を計測する
+///
+/// 実行方法: cargo bench --bench search_throughput
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use wasm_pkg::IntegratedSeedSearcher;
+
+const MAC: [u8; 6] = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+const NAZO: [u32; 5] = [0x02215f10, 0x01000000, 0xc0000000, 0x00007fff, 0x00000000];
+
+/// 1回の探索で処理する秒数（1日分）
+const RANGE_SECONDS: u32 = 86400;
+const TIMER0_MIN: u32 = 0xC79;
+const VCOUNT: u32 = 0x60;
+
+/// ヒットしない照合対象（探索コストのみを計測する）
+const TARGET_SEEDS: [u32; 4] = [0x00000000, 0x12345678, 0x9ABCDEF0, 0xFFFFFFFF];
+
+fn searcher() -> IntegratedSeedSearcher {
+    IntegratedSeedSearcher::try_new(&MAC, &NAZO, "DS", 0x2FFF, 8).expect("valid searcher parameters")
+}
+
+fn bench_per_core(c: &mut Criterion) {
+    let searcher = searcher();
+    let mut group = c.benchmark_group("search_per_core");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(RANGE_SECONDS as u64));
+
+    group.bench_function("search_seeds", |b| {
+        b.iter(|| {
+            searcher
+                .search_seeds(2012, 6, 15, 0, 0, 0, RANGE_SECONDS, TIMER0_MIN, TIMER0_MIN, VCOUNT, VCOUNT, &TARGET_SEEDS)
+                .unwrap()
+        })
+    });
+    group.bench_function("search_seeds_simd", |b| {
+        b.iter(|| {
+            searcher
+                .search_seeds_simd(2012, 6, 15, 0, 0, 0, RANGE_SECONDS, TIMER0_MIN, TIMER0_MIN, VCOUNT, VCOUNT, &TARGET_SEEDS)
+                .unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_all_cores(c: &mut Criterion) {
+    let searcher = searcher();
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as u32;
+    // Timer0ごとに1日分のワークアイテムができるため、コア数の2倍を割り当てて偏りを抑える
+    let timer0_max = TIMER0_MIN + cores * 2 - 1;
+
+    let mut group = c.benchmark_group("search_all_cores");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(RANGE_SECONDS as u64 * (cores * 2) as u64));
+
+    group.bench_function(format!("search_seeds_parallel/{}_threads", cores), |b| {
+        b.iter(|| {
+            searcher
+                .search_seeds_parallel(2012, 6, 15, 0, 0, 0, RANGE_SECONDS, TIMER0_MIN, timer0_max, VCOUNT, VCOUNT, &TARGET_SEEDS, 0)
+                .unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_per_core, bench_all_cores);
+criterion_main!(benches);
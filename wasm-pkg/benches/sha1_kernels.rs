@@ -0,0 +1,145 @@
+/// SHA-1カーネルのベンチマーク
+/// 完全メッセージ版と中間状態（ミッドステート）版のハッシュ/秒を比較する
+/// work_plan.rsのコストモデルもこの計測値から導出している
+///
+/// 実行方法: cargo bench --features bench --bench sha1_kernels
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use wasm_pkg::bench_support::{
+    calculate_pokemon_sha1, calculate_pokemon_sha1_simd, calculate_pokemon_sha1_simd_with_prefix,
+    calculate_pokemon_sha1_simd_x8, calculate_pokemon_sha1_simd_x8_with_prefix, calculate_pokemon_sha1_with_prefix,
+    Sha1Prefix,
+};
+
+/// 統合探索と同じ形式のメッセージ（data[9]が時刻コード）
+const MESSAGE: [u32; 16] = [
+    0x02215f10, 0x01000000, 0xc0000000, 0x00007fff,
+    0x00000000, 0x600c7900, 0x00009abc, 0x12345678,
+    0x12061505, 0x00000000, 0x00000000, 0x00000000,
+    0xff2f0000, 0x80000000, 0x00000000, 0x000001a0,
+];
+
+/// 1回の測定で処理する秒数（8レーン単位で割り切れる値）
+const SECONDS_PER_ITER: u32 = 1024;
+
+/// 秒インデックスから時刻コード風の値を作る
+fn time_code(second: u32) -> u32 {
+    0x52000000 | (second << 8)
+}
+
+fn bench_scalar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha1_scalar");
+    group.throughput(Throughput::Elements(SECONDS_PER_ITER as u64));
+
+    group.bench_function(BenchmarkId::new("full_message", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut message = MESSAGE;
+            let mut checksum = 0u32;
+            for second in 0..SECONDS_PER_ITER {
+                message[9] = time_code(second);
+                checksum ^= calculate_pokemon_sha1(black_box(&message)).0;
+            }
+            checksum
+        })
+    });
+
+    let prefix = Sha1Prefix::new(&MESSAGE);
+    group.bench_function(BenchmarkId::new("prefix", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut checksum = 0u32;
+            for second in 0..SECONDS_PER_ITER {
+                checksum ^= calculate_pokemon_sha1_with_prefix(black_box(&prefix), time_code(second)).0;
+            }
+            checksum
+        })
+    });
+
+    // 中間状態の構築（1日区間・レーンごとに1回）
+    group.bench_function(BenchmarkId::new("prefix_setup", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut message = MESSAGE;
+            let mut checksum = 0u32;
+            for second in 0..SECONDS_PER_ITER {
+                message[5] = time_code(second);
+                checksum ^= Sha1Prefix::new(black_box(&message)).state[0];
+            }
+            checksum
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_x4(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha1_simd_x4");
+    group.throughput(Throughput::Elements(SECONDS_PER_ITER as u64));
+
+    group.bench_function(BenchmarkId::new("full_message", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut messages = [0u32; 64];
+            for lane in 0..4 {
+                messages[lane * 16..(lane + 1) * 16].copy_from_slice(&MESSAGE);
+            }
+            let mut checksum = 0u32;
+            for second in (0..SECONDS_PER_ITER).step_by(4) {
+                for lane in 0..4 {
+                    messages[lane * 16 + 9] = time_code(second + lane as u32);
+                }
+                checksum ^= calculate_pokemon_sha1_simd(black_box(&messages))[0];
+            }
+            checksum
+        })
+    });
+
+    let prefix = Sha1Prefix::new(&MESSAGE);
+    group.bench_function(BenchmarkId::new("prefix", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut checksum = 0u32;
+            for second in (0..SECONDS_PER_ITER).step_by(4) {
+                let time_codes = [0, 1, 2, 3].map(|lane| time_code(second + lane));
+                checksum ^= calculate_pokemon_sha1_simd_with_prefix(black_box(&prefix), &time_codes)[0];
+            }
+            checksum
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_x8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha1_simd_x8");
+    group.throughput(Throughput::Elements(SECONDS_PER_ITER as u64));
+
+    group.bench_function(BenchmarkId::new("full_message", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut messages = [0u32; 128];
+            for lane in 0..8 {
+                messages[lane * 16..(lane + 1) * 16].copy_from_slice(&MESSAGE);
+            }
+            let mut checksum = 0u32;
+            for second in (0..SECONDS_PER_ITER).step_by(8) {
+                for lane in 0..8 {
+                    messages[lane * 16 + 9] = time_code(second + lane as u32);
+                }
+                checksum ^= calculate_pokemon_sha1_simd_x8(black_box(&messages))[0];
+            }
+            checksum
+        })
+    });
+
+    let prefix = Sha1Prefix::new(&MESSAGE);
+    group.bench_function(BenchmarkId::new("prefix", SECONDS_PER_ITER), |b| {
+        b.iter(|| {
+            let mut checksum = 0u32;
+            for second in (0..SECONDS_PER_ITER).step_by(8) {
+                let time_codes = [0, 1, 2, 3, 4, 5, 6, 7].map(|lane| time_code(second + lane));
+                checksum ^= calculate_pokemon_sha1_simd_x8_with_prefix(black_box(&prefix), &time_codes)[0];
+            }
+            checksum
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_scalar, bench_x4, bench_x8);
+criterion_main!(benches);
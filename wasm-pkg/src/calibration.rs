@@ -0,0 +1,275 @@
+/// 本体キャリブレーション
+/// 実機で特定できた初期シード（起動日時・キー入力・シード）からTimer0/VCountを逆算し、本体ごとの傾向として蓄積する。
+/// 蓄積結果は以降の探索範囲の絞り込みと、`Timer0VCountLikelihood`による結果の順位付けに使う
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::integrated_search::IntegratedSeedSearcher;
+use crate::likelihood::Timer0VCountLikelihood;
+use crate::multi_profile_search::SearchProfile;
+use crate::rom_parameters::VCountTimer0Range;
+use crate::target_matcher::TargetMatcher;
+
+/// 本体キャリブレーション
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsoleCalibration {
+    // (Timer0, VCount) → 観測回数（キー順で保持し、シリアライズ結果を安定させる）
+    counts: BTreeMap<(u32, u32), u32>,
+}
+
+#[wasm_bindgen]
+impl ConsoleCalibration {
+    /// 観測なしのキャリブレーションを作成
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ConsoleCalibration {
+        ConsoleCalibration::default()
+    }
+
+    /// 実機で特定できた初期シードを1件追加し、それを説明するTimer0/VCountの組の数を返す
+    /// 起動日時の1秒間を`key_input`で探索し、`targets`に一致した組が1つに定まった場合のみ記録する
+    #[allow(clippy::too_many_arguments)]  // Observation requires datetime, key input and candidate ranges
+    pub fn add_hit(
+        &mut self,
+        searcher: &IntegratedSeedSearcher,
+        year: u32,
+        month: u32,
+        date: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        key_input: u32,
+        targets: &TargetMatcher,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<u32, JsValue> {
+        Ok(self.try_add_hit(
+            searcher, year, month, date, hour, minute, second, key_input, targets,
+            timer0_min, timer0_max, vcount_min, vcount_max,
+        )?)
+    }
+
+    /// Timer0/VCountの組を直接1件記録
+    pub fn record(&mut self, timer0: u32, vcount: u32) {
+        let count = self.counts.entry((timer0, vcount)).or_insert(0);
+        *count = count.saturating_add(1);
+    }
+
+    /// 記録済みの観測件数（u32の上限で飽和する）
+    #[wasm_bindgen(getter)]
+    pub fn hit_count(&self) -> u32 {
+        self.counts.values().fold(0u32, |total, &count| total.saturating_add(count))
+    }
+
+    /// 観測されたTimer0の最小値（観測なしはundefined）
+    #[wasm_bindgen(getter)]
+    pub fn timer0_min(&self) -> Option<u32> {
+        self.counts.keys().map(|&(timer0, _)| timer0).min()
+    }
+
+    /// 観測されたTimer0の最大値（観測なしはundefined）
+    #[wasm_bindgen(getter)]
+    pub fn timer0_max(&self) -> Option<u32> {
+        self.counts.keys().map(|&(timer0, _)| timer0).max()
+    }
+
+    /// 観測されたVCountの最小値（観測なしはundefined）
+    #[wasm_bindgen(getter)]
+    pub fn vcount_min(&self) -> Option<u32> {
+        self.counts.keys().map(|&(_, vcount)| vcount).min()
+    }
+
+    /// 観測されたVCountの最大値（観測なしはundefined）
+    #[wasm_bindgen(getter)]
+    pub fn vcount_max(&self) -> Option<u32> {
+        self.counts.keys().map(|&(_, vcount)| vcount).max()
+    }
+
+    /// 観測結果の平坦配列（`[timer0, vcount, count]`の繰り返し、Timer0 → VCountの昇順）
+    #[wasm_bindgen(getter)]
+    pub fn observations(&self) -> Vec<u32> {
+        self.counts
+            .iter()
+            .flat_map(|(&(timer0, vcount), &count)| [timer0, vcount, count])
+            .collect()
+    }
+
+    /// 観測結果から絞り込んだ探索範囲の平坦配列（`[vcount, timer0_min, timer0_max]`の繰り返し、VCountの昇順）
+    /// `RomParameters::vcount_timer0_ranges`と同じ形式で、観測なしは空配列
+    #[wasm_bindgen(getter)]
+    pub fn narrowed_vcount_timer0_ranges(&self) -> Vec<u32> {
+        self.narrowed_ranges()
+            .iter()
+            .flat_map(|range| [range.vcount, range.timer0_min, range.timer0_max])
+            .collect()
+    }
+
+    /// 事前分布に観測結果を加えた尤度モデルを作成
+    pub fn likelihood(&self, prior: &Timer0VCountLikelihood) -> Timer0VCountLikelihood {
+        let mut likelihood = prior.clone();
+        for (&(timer0, vcount), &count) in &self.counts {
+            likelihood.observe_n(timer0, vcount, count);
+        }
+        likelihood
+    }
+
+    /// 文字列形式へシリアライズ（localStorage等への保存用）
+    pub fn serialize(&self) -> String {
+        let entries: Vec<String> = self.counts
+            .iter()
+            .map(|(&(timer0, vcount), &count)| format!("{timer0:x}.{vcount:x}.{count}"))
+            .collect();
+        format!("{}:{}", Self::FORMAT_VERSION, entries.join(","))
+    }
+
+    /// 文字列形式からデシリアライズ
+    pub fn deserialize(text: &str) -> Result<ConsoleCalibration, JsValue> {
+        Ok(Self::parse(text)?)
+    }
+}
+
+impl ConsoleCalibration {
+    /// シリアライズ形式のバージョン
+    const FORMAT_VERSION: &'static str = "v1";
+
+    /// 実機で特定できた初期シードを1件追加（ネイティブ版）
+    #[allow(clippy::too_many_arguments)]  // Observation requires datetime, key input and candidate ranges
+    pub fn try_add_hit(
+        &mut self,
+        searcher: &IntegratedSeedSearcher,
+        year: u32,
+        month: u32,
+        date: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        key_input: u32,
+        targets: &TargetMatcher,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<u32, InitSeedError> {
+        let explanations = searcher.with_key_input(key_input).search_seeds_with_targets(
+            year, month, date, hour, minute, second, 1,
+            timer0_min, timer0_max, vcount_min, vcount_max, targets,
+        )?;
+
+        if let [hit] = explanations.as_slice() {
+            self.record(hit.timer0(), hit.vcount());
+        }
+        Ok(explanations.len() as u32)
+    }
+
+    /// 観測されたVCountごとの、観測されたTimer0の最小値～最大値（VCountの昇順）
+    pub fn narrowed_ranges(&self) -> Vec<VCountTimer0Range> {
+        let mut ranges: BTreeMap<u32, VCountTimer0Range> = BTreeMap::new();
+        for &(timer0, vcount) in self.counts.keys() {
+            ranges
+                .entry(vcount)
+                .and_modify(|range| {
+                    range.timer0_min = range.timer0_min.min(timer0);
+                    range.timer0_max = range.timer0_max.max(timer0);
+                })
+                .or_insert(VCountTimer0Range { vcount, timer0_min: timer0, timer0_max: timer0 });
+        }
+        ranges.into_values().collect()
+    }
+
+    /// 絞り込んだ範囲を探索器に適用したプロファイルを作成（VCountごとに1つ）
+    /// 各プロファイルの尤度モデルには一様分布の事前分布に観測結果を加えたものを設定する
+    pub fn search_profiles(&self, searcher: &IntegratedSeedSearcher) -> Result<Vec<SearchProfile>, InitSeedError> {
+        let ranges = self.narrowed_ranges();
+        let likelihood = self.likelihood(&Timer0VCountLikelihood::from_ranges(ranges.clone()));
+        ranges
+            .iter()
+            .map(|range| {
+                let mut profile = SearchProfile::from_searcher(searcher.clone(), range.timer0_min, range.timer0_max, range.vcount, range.vcount)?;
+                profile.set_likelihood(&likelihood);
+                Ok(profile)
+            })
+            .collect()
+    }
+
+    /// 文字列形式からデシリアライズ（ネイティブ版）
+    pub fn parse(text: &str) -> Result<ConsoleCalibration, InitSeedError> {
+        let (version, body) = text.trim().split_once(':').ok_or(InitSeedError::InvalidCalibration)?;
+        if version != Self::FORMAT_VERSION {
+            return Err(InitSeedError::InvalidCalibration);
+        }
+
+        let mut calibration = ConsoleCalibration::new();
+        for entry in body.split(',').filter(|entry| !entry.is_empty()) {
+            let fields: Vec<&str> = entry.split('.').collect();
+            let [timer0, vcount, count] = fields.as_slice() else {
+                return Err(InitSeedError::InvalidCalibration);
+            };
+            let timer0 = u32::from_str_radix(timer0, 16).map_err(|_| InitSeedError::InvalidCalibration)?;
+            let vcount = u32::from_str_radix(vcount, 16).map_err(|_| InitSeedError::InvalidCalibration)?;
+            let count = count.parse::<u32>().map_err(|_| InitSeedError::InvalidCalibration)?;
+            let total = calibration.counts.entry((timer0, vcount)).or_insert(0);
+            *total = total.checked_add(count).ok_or(InitSeedError::InvalidCalibration)?;
+        }
+        Ok(calibration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let mut calibration = ConsoleCalibration::new();
+        assert_eq!(calibration.serialize(), "v1:");
+        assert_eq!(ConsoleCalibration::parse("v1:"), Ok(ConsoleCalibration::new()));
+
+        calibration.record(0xC7A, 0x60);
+        calibration.record(0xC79, 0x60);
+        calibration.record(0xC7A, 0x60);
+        assert_eq!(calibration.serialize(), "v1:c79.60.1,c7a.60.2");
+        assert_eq!(ConsoleCalibration::parse(&calibration.serialize()), Ok(calibration.clone()));
+        assert_eq!(calibration.observations(), vec![0xC79, 0x60, 1, 0xC7A, 0x60, 2]);
+        assert_eq!((calibration.timer0_min(), calibration.timer0_max()), (Some(0xC79), Some(0xC7A)));
+        assert_eq!(calibration.hit_count(), 3);
+
+        for malformed in ["", "v2:c79.60.1", "v1:c79.60", "v1:xyz.60.1", "v1:c79.60.-1", "v1:c79.60.4294967295,c79.60.1"] {
+            assert_eq!(ConsoleCalibration::parse(malformed), Err(InitSeedError::InvalidCalibration), "{malformed}");
+        }
+    }
+
+    #[test]
+    fn test_large_counts_saturate() {
+        // 観測回数が大きくても尤度モデルの作成は件数に比例しない
+        let calibration = ConsoleCalibration::parse("v1:c79.60.4294967295,c7a.60.4294967295,c7a.61.7").unwrap();
+        assert_eq!(calibration.hit_count(), u32::MAX);
+
+        let likelihood = calibration.likelihood(&Timer0VCountLikelihood::new(0xC79, 0xC7A, 0x60, 0x60));
+        assert_eq!(likelihood.observation_count(), u32::MAX);
+        assert!(likelihood.probability(0xC7A, 0x61) > 0.0);
+
+        let mut saturated = calibration.clone();
+        saturated.record(0xC79, 0x60);
+        assert_eq!(saturated, calibration);
+    }
+
+    #[test]
+    fn test_narrowed_ranges_follow_observations() {
+        let mut calibration = ConsoleCalibration::new();
+        assert!(calibration.narrowed_ranges().is_empty());
+
+        for (timer0, vcount) in [(0xC7A, 0x60), (0xC78, 0x60), (0xC79, 0x5F), (0xC7B, 0x60)] {
+            calibration.record(timer0, vcount);
+        }
+        assert_eq!(
+            calibration.narrowed_ranges(),
+            vec![
+                VCountTimer0Range { vcount: 0x5F, timer0_min: 0xC79, timer0_max: 0xC79 },
+                VCountTimer0Range { vcount: 0x60, timer0_min: 0xC78, timer0_max: 0xC7B },
+            ]
+        );
+        assert_eq!(calibration.narrowed_vcount_timer0_ranges(), vec![0x5F, 0xC79, 0xC79, 0x60, 0xC78, 0xC7B]);
+    }
+}
@@ -1,6 +1,9 @@
 /// 日時コード事前計算システム
 /// ポケモンBW/BW2の日時メッセージ生成における最大のボトルネック（BCD変換）を
 /// 事前計算テーブルで解決し、劇的な性能向上を実現する
+use crate::error::InitSeedError;
+use crate::hardware::HardwareProfile;
+
 /// 時刻コード事前計算テーブル (86,400エントリ = 24*60*60秒)
 /// 全ての時:分:秒の組み合わせを事前計算
 pub struct TimeCodeGenerator;
@@ -47,37 +50,20 @@ impl TimeCodeGenerator {
     pub const TIME_CODES: [u32; 86400] = Self::generate_all_time_codes();
     
     /// 時刻コードを高速取得（O(1)）
+    /// 日内秒が86400以上の場合はエラー
     #[inline]
-    pub fn get_time_code(seconds_of_day: u32) -> u32 {
-        let index = seconds_of_day as usize;
-        if index < Self::TIME_CODES.len() {
-            Self::TIME_CODES[index]
-        } else {
-            0 // エラー値
-        }
+    pub fn get_time_code(seconds_of_day: u32) -> Result<u32, InitSeedError> {
+        Self::TIME_CODES
+            .get(seconds_of_day as usize)
+            .copied()
+            .ok_or(InitSeedError::TimeOfDayOutOfRange(seconds_of_day))
     }
 
     /// Hardware-specific time code generation
-    /// DS/DS_LITE applies PM flag (0x40000000) for afternoon hours (>=12), 3DS uses 24-hour format
+    /// PMフラグの有無は`HardwareProfile`の既定値に従う（DS/DS_LITE/DSIは午後にPM flag、3DSは24時間表記）
     #[inline]
-    pub fn get_time_code_for_hardware(seconds_of_day: u32, hardware: &str) -> u32 {
-        let base_code = Self::get_time_code(seconds_of_day);
-        
-        // For DS and DS_LITE, add PM flag for afternoon hours
-        match hardware {
-            "DS" | "DS_LITE" => {
-                let hour = seconds_of_day / 3600;
-                if hour >= 12 {
-                    base_code | 0x40000000 // Add PM flag for afternoon
-                } else {
-                    base_code // Keep base code for morning
-                }
-            },
-            "3DS" => {
-                base_code // 3DS uses 24-hour format (no PM flag)
-            },
-            _ => base_code, // Fallback to base code
-        }
+    pub fn get_time_code_for_hardware(seconds_of_day: u32, hardware: &str) -> Result<u32, InitSeedError> {
+        HardwareProfile::parse(hardware)?.time_code(seconds_of_day)
     }
 }
 
@@ -88,7 +74,7 @@ pub struct DateCodeGenerator;
 impl DateCodeGenerator {
     /// 指定年がうるう年かどうか判定
     pub const fn is_leap_year(year: u32) -> bool {
-        (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+        (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
     }
     
     /// 月の日数を取得
@@ -167,13 +153,76 @@ impl DateCodeGenerator {
     pub const DATE_CODES: [u32; 36525] = Self::generate_all_date_codes();
     
     /// 日付コードを高速取得（O(1)）
+    /// 2099年12月31日より後の日はエラー
+    #[inline]
+    pub fn get_date_code(days_since_2000: u32) -> Result<u32, InitSeedError> {
+        Self::DATE_CODES
+            .get(days_since_2000 as usize)
+            .copied()
+            .ok_or(InitSeedError::DateOutOfRange)
+    }
+
+    /// 曜日の決め方を指定して日付コードを取得（O(1)）
+    #[inline]
+    pub fn get_date_code_with_weekday(days_since_2000: u32, rule: WeekdayRule) -> Result<u32, InitSeedError> {
+        Self::get_date_code(days_since_2000).map(|date_code| rule.apply(date_code))
+    }
+}
+
+/// RTCが報告する曜日の決め方
+/// 通常はグレゴリオ暦どおりだが、特殊な方法で時計を設定した本体では日付と曜日が一致しないことがある
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WeekdayRule {
+    /// 日付から計算した曜日（既定）
+    #[default]
+    Calendar,
+    /// 探索範囲の全日で同じ曜日（0=日曜 … 6=土曜）
+    Fixed(u32),
+    /// 暦上の曜日に加算する日数（0～6、日付が進むと曜日も進む）
+    Offset(u32),
+}
+
+impl WeekdayRule {
+    /// 曜日を固定する規則（0=日曜 … 6=土曜）
+    pub fn fixed(weekday: u32) -> Result<WeekdayRule, InitSeedError> {
+        if weekday >= 7 {
+            return Err(InitSeedError::InvalidWeekday(weekday));
+        }
+        Ok(WeekdayRule::Fixed(weekday))
+    }
+
+    /// 暦上の曜日をずらす規則（負の値は前の曜日へずらす）
+    pub fn offset(days: i32) -> WeekdayRule {
+        match days.rem_euclid(7) as u32 {
+            0 => WeekdayRule::Calendar,
+            days => WeekdayRule::Offset(days),
+        }
+    }
+
+    /// 規則を検証して正規化
+    /// 固定する曜日が0～6の範囲外ならエラー。ずらす日数は0～6に丸め、0日は暦どおりとする
+    pub fn validated(self) -> Result<WeekdayRule, InitSeedError> {
+        match self {
+            WeekdayRule::Calendar => Ok(WeekdayRule::Calendar),
+            WeekdayRule::Fixed(weekday) => WeekdayRule::fixed(weekday),
+            WeekdayRule::Offset(days) => Ok(WeekdayRule::offset((days % 7) as i32)),
+        }
+    }
+
+    /// 暦上の曜日に規則を適用
     #[inline]
-    pub fn get_date_code(days_since_2000: u32) -> u32 {
-        let index = days_since_2000 as usize;
-        if index < Self::DATE_CODES.len() {
-            Self::DATE_CODES[index]
-        } else {
-            0 // エラー値
+    pub fn weekday(self, calendar_weekday: u32) -> u32 {
+        match self {
+            WeekdayRule::Calendar => calendar_weekday,
+            WeekdayRule::Fixed(weekday) => weekday,
+            WeekdayRule::Offset(days) => (calendar_weekday + days) % 7,
         }
     }
+
+    /// 日付コード（YYMMDDWW）の曜日部分を置き換える
+    #[inline]
+    pub fn apply(self, date_code: u32) -> u32 {
+        // 曜日は0～6なのでBCDでも下位1桁のみ
+        (date_code & !0xFF) | self.weekday(date_code & 0xFF)
+    }
 }
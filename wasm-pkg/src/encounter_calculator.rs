@@ -58,6 +58,12 @@ pub enum DustCloudContent {
 #[wasm_bindgen]
 pub struct EncounterCalculator;
 
+impl Default for EncounterCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl EncounterCalculator {
     /// 新しいEncounterCalculatorインスタンスを作成
@@ -392,8 +398,8 @@ mod tests {
         assert_eq!(dist[4], 1);  // 1%
         
         // 未使用スロットは0
-        for i in 5..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[5..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -412,8 +418,8 @@ mod tests {
         assert_eq!(dist[4], 0);  // レアスロット（0-99範囲外）
         
         // 未使用スロットは0
-        for i in 5..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[5..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -432,8 +438,8 @@ mod tests {
         assert_eq!(dist[4], 5);  // 5%
         
         // 未使用スロットは0
-        for i in 5..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[5..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -450,8 +456,8 @@ mod tests {
         assert_eq!(dist[2], 10); // 10%
         
         // 未使用スロットは0
-        for i in 3..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[3..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -469,8 +475,8 @@ mod tests {
         assert_eq!(dist[3], 5);  // 5%
         
         // 未使用スロットは0
-        for i in 4..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[4..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -488,8 +494,8 @@ mod tests {
         assert_eq!(dist[3], 5);  // 5%
         
         // 未使用スロットは0
-        for i in 4..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[4..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -507,8 +513,8 @@ mod tests {
         assert_eq!(dist[3], 5);  // 5%
         
         // 未使用スロットは0
-        for i in 4..12 {
-            assert_eq!(dist[i], 0);
+        for &count in &dist[4..12] {
+            assert_eq!(count, 0);
         }
     }
 
@@ -0,0 +1,201 @@
+/// クレート共通エラー
+/// 探索・日時コード・チェックポイント等の公開APIはこのエラーを返す。
+/// wasm_bindgen公開メソッドではJSの`Error`（`name`が`InitSeedError`、`code`に種別コード）として送出される
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// クレート共通エラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitSeedError {
+    /// 存在しない日付・時刻
+    InvalidDate { year: u32, month: u32, day: u32, hour: u32, minute: u32, second: u32 },
+    /// 探索範囲がDSの時計の範囲（2000-01-01 00:00:00 ～ 2099-12-31 23:59:59）を外れる
+    DateOutOfRange,
+    /// 終了日が開始日より前
+    ReversedDateRange,
+    /// 日内秒が0～86399の範囲外
+    TimeOfDayOutOfRange(u32),
+    /// 曜日が0～6の範囲外
+    InvalidWeekday(u32),
+    /// Timer0範囲が不正（最小値が最大値を超える、または16bitを超える）
+    InvalidTimer0Range { min: u32, max: u32 },
+    /// VCount範囲が不正（最小値が最大値を超える、または16bitを超える）
+    InvalidVCountRange { min: u32, max: u32 },
+    /// 未知のハードウェア種別
+    UnknownHardware(String),
+    /// フレーム値の既定値がない本体種別でフレーム値が指定されていない
+    MissingFrame(String),
+    /// 未知のROMバージョン
+    UnknownRomVersion(String),
+    /// 未知のROM言語
+    UnknownRomRegion(String),
+    /// このROMのnazo値が本体のnazo系統（DSi・3DS）向けに用意されていない
+    UnsupportedRomHardware { version: String, region: String },
+    /// MACアドレスが6バイトでない
+    InvalidMacLength(usize),
+    /// nazoが5ワードでない
+    InvalidNazoLength(usize),
+    /// 16進数として解釈できない、または32bitを超える文字列
+    InvalidHexString(String),
+    /// ボタン以外のビットを含むキーマスク
+    InvalidKeyMask(u32),
+    /// 日次時間帯が`start < end <= 86400`を満たさない
+    InvalidDailyWindow { start: u32, end: u32 },
+    /// 日次時間帯の平坦配列が開始・終了の組になっていない
+    UnpairedDailyWindow,
+    /// チェックポイント文字列の形式・バージョンが不正、または位置が探索範囲外
+    InvalidCheckpoint,
+    /// 探索器の設定がチェックポイント保存時と異なる
+    CheckpointMismatch,
+    /// キャリブレーション文字列の形式・バージョンが不正
+    InvalidCalibration,
+    /// シードインデックスの形式・バージョンが不正
+    InvalidSeedIndex,
+    /// シードインデックスの候補数が上限（2^32）を超える
+    SeedIndexTooLarge,
+    /// 探索単位の総数がu64に収まらない
+    TooManySearchUnits,
+    /// シード列挙の候補数が1つの配列に収まらない
+    SeedListTooLarge,
+    /// ワークユニット分割計画の形式・バージョンが不正
+    InvalidWorkPlan,
+    /// 探索器の設定が分割計画の作成時と異なる
+    WorkPlanMismatch,
+    /// 分割計画に存在しないワークユニットID
+    UnknownWorkUnit(u32),
+    /// TID/SIDが決まらないゲームモード（「続きから」）
+    NoTidSidForMode(u32),
+    /// 条件を1つも持たないTID/SID探索ターゲット
+    EmptyTidSidTarget,
+    /// 頑健性評価の半径が上限を超える
+    RobustnessRadiusTooLarge(u32),
+    /// 探索ジョブ仕様の形式・内容が不正
+    InvalidJobSpec(String),
+    /// 未対応の探索ジョブ仕様バージョン
+    UnsupportedJobVersion(u32),
+    /// 結果レコードのバッファ長が不正
+    InvalidResultRecords,
+    /// 結果レコードが存在しないプロファイルを参照している
+    UnknownProfile(u32),
+}
+
+impl InitSeedError {
+    /// JS側で分岐に使う安定した種別コード
+    pub fn code(&self) -> &'static str {
+        match self {
+            InitSeedError::InvalidDate { .. } => "INVALID_DATE",
+            InitSeedError::DateOutOfRange => "DATE_OUT_OF_RANGE",
+            InitSeedError::ReversedDateRange => "REVERSED_DATE_RANGE",
+            InitSeedError::TimeOfDayOutOfRange(_) => "TIME_OF_DAY_OUT_OF_RANGE",
+            InitSeedError::InvalidWeekday(_) => "INVALID_WEEKDAY",
+            InitSeedError::InvalidTimer0Range { .. } => "INVALID_TIMER0_RANGE",
+            InitSeedError::InvalidVCountRange { .. } => "INVALID_VCOUNT_RANGE",
+            InitSeedError::UnknownHardware(_) => "UNKNOWN_HARDWARE",
+            InitSeedError::MissingFrame(_) => "MISSING_FRAME",
+            InitSeedError::UnknownRomVersion(_) => "UNKNOWN_ROM_VERSION",
+            InitSeedError::UnknownRomRegion(_) => "UNKNOWN_ROM_REGION",
+            InitSeedError::UnsupportedRomHardware { .. } => "UNSUPPORTED_ROM_HARDWARE",
+            InitSeedError::InvalidMacLength(_) => "INVALID_MAC_LENGTH",
+            InitSeedError::InvalidNazoLength(_) => "INVALID_NAZO_LENGTH",
+            InitSeedError::InvalidHexString(_) => "INVALID_HEX_STRING",
+            InitSeedError::InvalidKeyMask(_) => "INVALID_KEY_MASK",
+            InitSeedError::InvalidDailyWindow { .. } => "INVALID_DAILY_WINDOW",
+            InitSeedError::UnpairedDailyWindow => "UNPAIRED_DAILY_WINDOW",
+            InitSeedError::InvalidCheckpoint => "INVALID_CHECKPOINT",
+            InitSeedError::CheckpointMismatch => "CHECKPOINT_MISMATCH",
+            InitSeedError::InvalidCalibration => "INVALID_CALIBRATION",
+            InitSeedError::InvalidSeedIndex => "INVALID_SEED_INDEX",
+            InitSeedError::SeedIndexTooLarge => "SEED_INDEX_TOO_LARGE",
+            InitSeedError::TooManySearchUnits => "TOO_MANY_SEARCH_UNITS",
+            InitSeedError::SeedListTooLarge => "SEED_LIST_TOO_LARGE",
+            InitSeedError::InvalidWorkPlan => "INVALID_WORK_PLAN",
+            InitSeedError::WorkPlanMismatch => "WORK_PLAN_MISMATCH",
+            InitSeedError::UnknownWorkUnit(_) => "UNKNOWN_WORK_UNIT",
+            InitSeedError::NoTidSidForMode(_) => "NO_TID_SID_FOR_MODE",
+            InitSeedError::EmptyTidSidTarget => "EMPTY_TID_SID_TARGET",
+            InitSeedError::RobustnessRadiusTooLarge(_) => "ROBUSTNESS_RADIUS_TOO_LARGE",
+            InitSeedError::InvalidJobSpec(_) => "INVALID_JOB_SPEC",
+            InitSeedError::UnsupportedJobVersion(_) => "UNSUPPORTED_JOB_VERSION",
+            InitSeedError::InvalidResultRecords => "INVALID_RESULT_RECORDS",
+            InitSeedError::UnknownProfile(_) => "UNKNOWN_PROFILE",
+        }
+    }
+}
+
+impl fmt::Display for InitSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitSeedError::InvalidDate { year, month, day, hour, minute, second } => {
+                write!(f, "invalid date/time {year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}")
+            }
+            InitSeedError::DateOutOfRange => {
+                write!(f, "search range must lie within 2000-01-01 00:00:00 and 2099-12-31 23:59:59")
+            }
+            InitSeedError::ReversedDateRange => write!(f, "end date must not be earlier than start date"),
+            InitSeedError::TimeOfDayOutOfRange(seconds) => write!(f, "seconds of day {seconds} must be less than 86400"),
+            InitSeedError::InvalidWeekday(weekday) => write!(f, "weekday {weekday} must be 0 (Sunday) to 6 (Saturday)"),
+            InitSeedError::InvalidTimer0Range { min, max } => {
+                write!(f, "invalid timer0 range {min:#x}-{max:#x} (min must not exceed max, max 0xFFFF)")
+            }
+            InitSeedError::InvalidVCountRange { min, max } => {
+                write!(f, "invalid vcount range {min:#x}-{max:#x} (min must not exceed max, max 0xFFFF)")
+            }
+            InitSeedError::UnknownHardware(hardware) => write!(f, "unknown hardware '{hardware}' (expected DS, DS_LITE, DSI or 3DS)"),
+            InitSeedError::MissingFrame(hardware) => {
+                write!(f, "hardware {hardware} has no verified default frame value; specify the frame explicitly")
+            }
+            InitSeedError::UnknownRomVersion(version) => write!(f, "unknown ROM version '{version}' (expected B, W, B2 or W2)"),
+            InitSeedError::UnknownRomRegion(region) => {
+                write!(f, "unknown ROM region '{region}' (expected JPN, KOR, USA, GER, FRA, SPA or ITA)")
+            }
+            InitSeedError::UnsupportedRomHardware { version, region } => {
+                write!(f, "no DSi/3DS nazo values are available for ROM {version} {region}")
+            }
+            InitSeedError::InvalidMacLength(length) => write!(f, "MAC address must be 6 bytes, got {length}"),
+            InitSeedError::InvalidNazoLength(length) => write!(f, "nazo must be 5 32-bit words, got {length}"),
+            InitSeedError::InvalidHexString(text) => write!(f, "'{text}' is not a 32-bit hexadecimal number"),
+            InitSeedError::InvalidKeyMask(mask) => write!(f, "key mask {mask:#x} must only contain button bits (0x0FFF)"),
+            InitSeedError::InvalidDailyWindow { start, end } => {
+                write!(f, "daily window {start}-{end} must satisfy start < end <= 86400")
+            }
+            InitSeedError::UnpairedDailyWindow => write!(f, "daily windows must be pairs of start and end seconds"),
+            InitSeedError::InvalidCheckpoint => write!(f, "malformed or unsupported search checkpoint"),
+            InitSeedError::CheckpointMismatch => write!(f, "checkpoint was taken with a different searcher configuration"),
+            InitSeedError::InvalidCalibration => write!(f, "malformed or unsupported console calibration"),
+            InitSeedError::InvalidSeedIndex => write!(f, "malformed or unsupported seed index"),
+            InitSeedError::SeedIndexTooLarge => write!(f, "seed index must contain fewer than 2^32 candidates"),
+            InitSeedError::TooManySearchUnits => write!(f, "search unit count does not fit in 64 bits"),
+            InitSeedError::SeedListTooLarge => write!(f, "too many candidates to list in a single array"),
+            InitSeedError::InvalidWorkPlan => write!(f, "malformed or unsupported work plan"),
+            InitSeedError::WorkPlanMismatch => write!(f, "work plan was created with a different searcher configuration"),
+            InitSeedError::UnknownWorkUnit(id) => write!(f, "work plan has no unit {id}"),
+            InitSeedError::NoTidSidForMode(mode) => {
+                write!(f, "game mode {mode} continues a save and does not determine TID/SID")
+            }
+            InitSeedError::EmptyTidSidTarget => {
+                write!(f, "TID/SID target: at least one condition (TID, SID or shiny PID) is required")
+            }
+            InitSeedError::RobustnessRadiusTooLarge(radius) => {
+                write!(f, "robustness radius {radius} exceeds the maximum of {}", crate::robustness::MAX_ROBUSTNESS_RADIUS)
+            }
+            InitSeedError::InvalidJobSpec(message) => write!(f, "invalid search job: {message}"),
+            InitSeedError::UnsupportedJobVersion(version) => {
+                write!(f, "unsupported search job version {version} (expected {})", crate::job_spec::JOB_SPEC_VERSION)
+            }
+            InitSeedError::InvalidResultRecords => write!(f, "result buffer length must be a multiple of the record size"),
+            InitSeedError::UnknownProfile(index) => write!(f, "result record refers to unknown profile {index}"),
+        }
+    }
+}
+
+impl std::error::Error for InitSeedError {}
+
+impl From<InitSeedError> for JsValue {
+    fn from(error: InitSeedError) -> JsValue {
+        let js_error = js_sys::Error::new(&error.to_string());
+        js_error.set_name("InitSeedError");
+        // codeの設定に失敗してもメッセージは伝わるため無視する
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from_str(error.code()));
+        js_error.into()
+    }
+}
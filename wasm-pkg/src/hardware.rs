@@ -0,0 +1,230 @@
+/// ハードウェアプロファイル
+/// 本体ごとに異なる初期シード計算用メッセージの要素（PMフラグ・GxStat・フレーム値・nazoの系統）をまとめる
+use wasm_bindgen::prelude::*;
+use crate::datetime_codes::TimeCodeGenerator;
+use crate::error::InitSeedError;
+
+/// ハードウェア種別
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hardware {
+    /// ニンテンドーDS
+    Ds = 0,
+    /// ニンテンドーDS Lite
+    DsLite = 1,
+    /// ニンテンドーDSi / DSi LL
+    Dsi = 2,
+    /// ニンテンドー3DS / 3DS LL / 2DS
+    ThreeDs = 3,
+}
+
+impl Hardware {
+    /// 種別名（"DS" / "DS_LITE" / "DSI" / "3DS"）から変換
+    pub fn parse(name: &str) -> Result<Hardware, InitSeedError> {
+        match name {
+            "DS" => Ok(Hardware::Ds),
+            "DS_LITE" => Ok(Hardware::DsLite),
+            "DSI" => Ok(Hardware::Dsi),
+            "3DS" => Ok(Hardware::ThreeDs),
+            _ => Err(InitSeedError::UnknownHardware(name.to_string())),
+        }
+    }
+
+    /// 種別名（TypeScript側の`Hardware`型と同じ表記）
+    pub fn name(self) -> &'static str {
+        match self {
+            Hardware::Ds => "DS",
+            Hardware::DsLite => "DS_LITE",
+            Hardware::Dsi => "DSI",
+            Hardware::ThreeDs => "3DS",
+        }
+    }
+}
+
+/// nazo値の系統
+/// DSi・3DSではROMがDSiモードのメモリ配置で動作するため、一部のROMでnazo値がDS・DS Liteと異なる。
+/// `RomParameters`はバージョン・言語とこの系統の組でnazo値を引く
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NazoVariant {
+    /// DS・DS Lite
+    Ds = 0,
+    /// DSi・3DS
+    Dsi = 1,
+}
+
+/// ハードウェアプロファイル
+/// 既定値は本体種別から決まり、実機で値が異なる場合は`with_*`で上書きできる。
+/// フレーム値の既定値はTypeScript版の表（DS: 8, DS Lite: 6, 3DS: 9）に従い、
+/// 表にないDSiは`with_frame`で指定するまで探索器を作成できない
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HardwareProfile {
+    hardware: Hardware,
+    pm_flag: bool,
+    gx_stat: u32,
+    frame: Option<u32>,
+}
+
+#[wasm_bindgen]
+impl HardwareProfile {
+    /// 本体種別の既定値でプロファイルを作成
+    #[wasm_bindgen(constructor)]
+    pub fn new(hardware: Hardware) -> HardwareProfile {
+        let (pm_flag, frame) = match hardware {
+            Hardware::Ds => (true, Some(8)),
+            Hardware::DsLite => (true, Some(6)),
+            // DSiのフレーム値は実測値がないため既定値を持たない
+            Hardware::Dsi => (true, None),
+            // 3DSは24時間表記のためPMフラグが立たない
+            Hardware::ThreeDs => (false, Some(9)),
+        };
+
+        HardwareProfile { hardware, pm_flag, gx_stat: Self::DEFAULT_GX_STAT, frame }
+    }
+
+    /// 種別名から既定値のプロファイルを作成
+    pub fn from_name(name: &str) -> Result<HardwareProfile, JsValue> {
+        Ok(Self::parse(name)?)
+    }
+
+    /// フレーム値を上書き
+    pub fn with_frame(self, frame: u32) -> HardwareProfile {
+        HardwareProfile { frame: Some(frame), ..self }
+    }
+
+    /// GxStatを上書き
+    pub fn with_gx_stat(self, gx_stat: u32) -> HardwareProfile {
+        HardwareProfile { gx_stat, ..self }
+    }
+
+    /// 午後（12時以降）にPMフラグを立てるかを上書き
+    pub fn with_pm_flag(self, pm_flag: bool) -> HardwareProfile {
+        HardwareProfile { pm_flag, ..self }
+    }
+
+    /// ハードウェア種別
+    #[wasm_bindgen(getter)]
+    pub fn hardware(&self) -> Hardware {
+        self.hardware
+    }
+
+    /// 種別名
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.hardware.name().to_string()
+    }
+
+    /// 午後にPMフラグ（0x40000000）を立てるか
+    #[wasm_bindgen(getter)]
+    pub fn pm_flag(&self) -> bool {
+        self.pm_flag
+    }
+
+    /// GxStat
+    #[wasm_bindgen(getter)]
+    pub fn gx_stat(&self) -> u32 {
+        self.gx_stat
+    }
+
+    /// フレーム値（既定値がなく未指定の場合はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn frame(&self) -> Option<u32> {
+        self.frame
+    }
+
+    /// この本体で使用するnazo値の系統（`IntegratedSeedSearcher::from_rom`のROMパラメータ選択に使う）
+    #[wasm_bindgen(getter)]
+    pub fn nazo_variant(&self) -> NazoVariant {
+        match self.hardware {
+            Hardware::Ds | Hardware::DsLite => NazoVariant::Ds,
+            Hardware::Dsi | Hardware::ThreeDs => NazoVariant::Dsi,
+        }
+    }
+}
+
+impl HardwareProfile {
+    /// GxStatの既定値
+    pub const DEFAULT_GX_STAT: u32 = 0x06000000;
+    /// 時刻コードのPMフラグ
+    pub const PM_FLAG: u32 = 0x40000000;
+
+    /// 種別名から既定値のプロファイルを作成（ネイティブ版）
+    pub fn parse(name: &str) -> Result<HardwareProfile, InitSeedError> {
+        Hardware::parse(name).map(HardwareProfile::new)
+    }
+
+    /// 日内秒の時刻コード（PMフラグ適用済み）
+    #[inline]
+    pub fn time_code(&self, seconds_of_day: u32) -> Result<u32, InitSeedError> {
+        let base_code = TimeCodeGenerator::get_time_code(seconds_of_day)?;
+        if self.pm_flag && seconds_of_day >= 12 * 3600 {
+            Ok(base_code | Self::PM_FLAG)
+        } else {
+            Ok(base_code)
+        }
+    }
+
+    /// メッセージdata[7]に入るMAC上位32bitとの排他的論理和の値
+    /// フレーム値が未指定ならエラー
+    pub(crate) fn data7_mask(&self) -> Result<u32, InitSeedError> {
+        let frame = self.frame.ok_or_else(|| InitSeedError::MissingFrame(self.hardware.name().to_string()))?;
+        Ok(self.gx_stat ^ frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_profiles() {
+        let ds = HardwareProfile::new(Hardware::Ds);
+        assert_eq!((ds.frame(), ds.gx_stat(), ds.pm_flag()), (Some(8), 0x06000000, true));
+        assert_eq!(HardwareProfile::new(Hardware::DsLite).frame(), Some(6));
+        assert_eq!(HardwareProfile::new(Hardware::ThreeDs).frame(), Some(9));
+        assert_eq!(HardwareProfile::new(Hardware::Dsi).frame(), None);
+        assert!(!HardwareProfile::new(Hardware::ThreeDs).pm_flag());
+
+        assert_eq!(ds.nazo_variant(), NazoVariant::Ds);
+        assert_eq!(HardwareProfile::new(Hardware::Dsi).nazo_variant(), NazoVariant::Dsi);
+        assert_eq!(HardwareProfile::new(Hardware::ThreeDs).nazo_variant(), NazoVariant::Dsi);
+    }
+
+    #[test]
+    fn test_nazo_variant_selects_rom_parameters() {
+        use crate::rom_parameters::{RomParameters, RomRegion, RomVersion};
+
+        for hardware in [Hardware::Ds, Hardware::DsLite, Hardware::Dsi, Hardware::ThreeDs] {
+            // フレーム値等の上書きはnazo系統に影響しない
+            let profile = HardwareProfile::new(hardware).with_frame(5).with_pm_flag(false);
+            assert_eq!(profile.nazo_variant(), HardwareProfile::new(hardware).nazo_variant());
+
+            let rom = RomParameters::for_hardware(RomVersion::White2, RomRegion::Usa, &profile).unwrap();
+            assert_eq!(rom.nazo_variant(), profile.nazo_variant());
+            assert_eq!(
+                RomParameters::for_hardware(RomVersion::White, RomRegion::Usa, &profile).is_ok(),
+                profile.nazo_variant() == NazoVariant::Ds
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_and_overrides() {
+        for hardware in [Hardware::Ds, Hardware::DsLite, Hardware::Dsi, Hardware::ThreeDs] {
+            assert_eq!(Hardware::parse(hardware.name()), Ok(hardware));
+        }
+        assert_eq!(HardwareProfile::parse("GBA"), Err(InitSeedError::UnknownHardware("GBA".to_string())));
+
+        let profile = HardwareProfile::new(Hardware::Ds).with_frame(5).with_gx_stat(0x06000001).with_pm_flag(false);
+        assert_eq!(profile.data7_mask(), Ok(0x06000001 ^ 5));
+
+        // DSiはフレーム値を指定するまでメッセージを組み立てられない
+        let dsi = HardwareProfile::new(Hardware::Dsi);
+        assert_eq!(dsi.data7_mask(), Err(InitSeedError::MissingFrame("DSI".to_string())));
+        assert_eq!(dsi.with_frame(8).data7_mask(), Ok(0x06000000 ^ 8));
+        assert_eq!(profile.time_code(13 * 3600), Ok(0x13000000));
+        assert_eq!(HardwareProfile::new(Hardware::Ds).time_code(13 * 3600), Ok(0x53000000));
+        assert_eq!(HardwareProfile::new(Hardware::Ds).time_code(11 * 3600), Ok(0x11000000));
+    }
+}
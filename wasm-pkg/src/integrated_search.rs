@@ -1,14 +1,29 @@
 /// 統合シード探索システム
 /// メッセージ生成とSHA-1計算を一体化し、WebAssembly内で完結する高速探索を実現
 use wasm_bindgen::prelude::*;
-use std::collections::BTreeSet;
-use crate::datetime_codes::{TimeCodeGenerator, DateCodeGenerator};
+use crate::datetime_codes::{DateCodeGenerator, WeekdayRule};
+use crate::key_input::{KeyInputEnumerator, KEY_BUTTONS_ALL, KEY_INPUT_NONE};
+use crate::pokemon_generator::{BWGenerationConfig, PokemonGenerator, RawPokemonData, SeedEnumerator};
+use crate::target_matcher::TargetMatcher;
+use crate::search_control::{fnv1a_64, CancellationToken, SearchCursor, SearchOutcome, SearchPosition, SearchProgress};
+use crate::personality_rng::PersonalityRNG;
+use crate::result_records::ResultRecord;
+use crate::error::InitSeedError;
+use crate::hardware::HardwareProfile;
+use crate::rom_parameters::{RomParameters, RomRegion, RomVersion, VCountTimer0Range};
+use crate::likelihood::Timer0VCountLikelihood;
+use crate::robustness::RobustnessWindow;
+use crate::offset_calculator::{calculate_tid_sid_from_seed, GameMode, TidSidResult};
+use crate::tid_sid_target::TidSidTarget;
 
 // コンパイル時最適化のためのアトリビュート
 #[cfg(target_family = "wasm")]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
-use crate::sha1::{calculate_pokemon_sha1, swap_bytes_32};
+use crate::sha1::{
+    calculate_pokemon_lcg_seed_from_hash, calculate_pokemon_seed_from_hash, calculate_pokemon_sha1,
+    calculate_pokemon_sha1_with_prefix, swap_bytes_32, Sha1Prefix,
+};
 use chrono::{NaiveDate, Datelike, Timelike};
 
 // Import the `console.log` function from the browser console
@@ -21,16 +36,96 @@ extern "C" {
 /// 2000年1月1日 00:00:00 UTCのUnix時間
 const EPOCH_2000_UNIX: i64 = 946684800;
 
+/// SIMD探索で1回に処理する秒数（8レーンSHA-1のバッチ幅）
+pub(crate) const SIMD_BATCH_SIZE: u32 = 8;
+
 /// 検索パラメータ構造体（内部用）
 #[derive(Clone, Copy)]
-struct SearchParams {
-    timer0: u32,
-    vcount: u32,
+pub(crate) struct SearchParams {
+    pub(crate) timer0: u32,
+    pub(crate) vcount: u32,
+    pub(crate) key_input: u32,
+}
+
+/// 探索範囲構造体（内部用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SearchRange {
+    pub(crate) base_seconds_since_2000: i64,
+    pub(crate) range_seconds: u32,
+    pub(crate) timer0_min: u32,
+    pub(crate) timer0_max: u32,
+    pub(crate) vcount_min: u32,
+    pub(crate) vcount_max: u32,
+}
+
+impl SearchRange {
+    /// DSの時計で表現できる秒数（2000-01-01 00:00:00 ～ 2099-12-31 23:59:59）
+    const SUPPORTED_SECONDS: i64 = DateCodeGenerator::DATE_CODES.len() as i64 * 86400;
+
+    /// 開始日時と各範囲から探索範囲を構築し、検証する
+    #[allow(clippy::too_many_arguments)]  // Range requires all datetime and hardware bounds
+    pub(crate) fn from_datetime(
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SearchRange, InitSeedError> {
+        let base_seconds_since_2000 = IntegratedSeedSearcher::seconds_since_2000(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+        )?;
+        let range = SearchRange {
+            base_seconds_since_2000,
+            range_seconds,
+            timer0_min,
+            timer0_max,
+            vcount_min,
+            vcount_max,
+        };
+        range.validate()?;
+        Ok(range)
+    }
+
+    /// 日時範囲とTimer0/VCount範囲を検証
+    pub(crate) fn validate(&self) -> Result<(), InitSeedError> {
+        Self::check_seconds(self.base_seconds_since_2000, self.range_seconds)?;
+        Self::check_bounds(self.timer0_min, self.timer0_max, self.vcount_min, self.vcount_max)
+    }
+
+    /// 開始秒から`range_seconds`秒間がDSの時計の範囲内か検証
+    pub(crate) fn check_seconds(base_seconds_since_2000: i64, range_seconds: u32) -> Result<(), InitSeedError> {
+        Self::check_span(base_seconds_since_2000, base_seconds_since_2000 + range_seconds as i64)
+    }
+
+    /// 半開区間`[start, end)`（2000年1月1日からの経過秒）がDSの時計の範囲内か検証
+    pub(crate) fn check_span(start_seconds_since_2000: i64, end_seconds_since_2000: i64) -> Result<(), InitSeedError> {
+        if start_seconds_since_2000 < 0 || end_seconds_since_2000 > Self::SUPPORTED_SECONDS {
+            return Err(InitSeedError::DateOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Timer0/VCount範囲を検証（メッセージ上はそれぞれ16bit）
+    pub(crate) fn check_bounds(timer0_min: u32, timer0_max: u32, vcount_min: u32, vcount_max: u32) -> Result<(), InitSeedError> {
+        if timer0_min > timer0_max || timer0_max > 0xFFFF {
+            return Err(InitSeedError::InvalidTimer0Range { min: timer0_min, max: timer0_max });
+        }
+        if vcount_min > vcount_max || vcount_max > 0xFFFF {
+            return Err(InitSeedError::InvalidVCountRange { min: vcount_min, max: vcount_max });
+        }
+        Ok(())
+    }
 }
 
 /// ハッシュ値構造体（内部用）
 #[derive(Clone, Copy)]
-struct HashValues {
+pub(crate) struct HashValues {
     h0: u32,
     h1: u32,
     h2: u32,
@@ -52,6 +147,18 @@ pub struct SearchResult {
     second: u32,
     timer0: u32,
     vcount: u32,
+    key_input: u32,
+    profile_index: u32,
+    // 再現・検証用の探索入力
+    mac: [u8; 6],
+    nazo: [u32; 5],
+    hardware: Option<HardwareProfile>,
+    message: [u32; 16],
+    lcg_seed: u64,
+    // 頑健性評価（周辺で一致した数, 評価した周辺の数）
+    robustness: Option<(u32, u32)>,
+    // 「始めから」で決まるTID/SID（TID/SID探索の結果のみ）
+    tid_sid: Option<TidSidResult>,
 }
 
 #[wasm_bindgen]
@@ -59,7 +166,18 @@ impl SearchResult {
     #[wasm_bindgen(constructor)]
     #[allow(clippy::too_many_arguments)]  // WebAssembly constructor requires all parameters
     pub fn new(seed: u32, hash: String, year: u32, month: u32, date: u32, hour: u32, minute: u32, second: u32, timer0: u32, vcount: u32) -> SearchResult {
-        SearchResult { seed, hash, year, month, date, hour, minute, second, timer0, vcount }
+        SearchResult {
+            seed, hash, year, month, date, hour, minute, second, timer0, vcount,
+            key_input: KEY_INPUT_NONE,
+            profile_index: 0,
+            mac: [0; 6],
+            nazo: [0; 5],
+            hardware: None,
+            message: [0; 16],
+            lcg_seed: 0,
+            robustness: None,
+            tid_sid: None,
+        }
     }
     
     #[wasm_bindgen(getter)]
@@ -82,17 +200,146 @@ impl SearchResult {
     pub fn timer0(&self) -> u32 { self.timer0 }
     #[wasm_bindgen(getter)]
     pub fn vcount(&self) -> u32 { self.vcount }
+    /// ヒットしたメッセージのkey_input値（押下ボタンのビットが0）
+    #[wasm_bindgen(getter)]
+    pub fn key_input(&self) -> u32 { self.key_input }
+    /// key_input値を設定（コンストラクタでは未入力の0x2FFFになる）
+    #[wasm_bindgen(setter)]
+    pub fn set_key_input(&mut self, key_input: u32) { self.key_input = key_input; }
+    /// 複数プロファイル探索でヒットしたプロファイルの番号（単一探索では0）
+    #[wasm_bindgen(getter)]
+    pub fn profile_index(&self) -> u32 { self.profile_index }
+    /// 探索に使用したMACアドレス（6バイト）
+    #[wasm_bindgen(getter)]
+    pub fn mac(&self) -> Vec<u8> { self.mac.to_vec() }
+    /// 探索に使用したnazo値（5ワード）
+    #[wasm_bindgen(getter)]
+    pub fn nazo(&self) -> Vec<u32> { self.nazo.to_vec() }
+    /// 探索に使用したハードウェア種別
+    #[wasm_bindgen(getter)]
+    pub fn hardware(&self) -> String { self.hardware.map(|profile| profile.name()).unwrap_or_default() }
+    /// 探索に使用したフレーム値
+    #[wasm_bindgen(getter)]
+    pub fn frame(&self) -> u32 { self.hardware.and_then(|profile| profile.frame()).unwrap_or(0) }
+    /// 探索に使用したハードウェアプロファイル
+    #[wasm_bindgen(getter)]
+    pub fn hardware_profile(&self) -> Option<HardwareProfile> { self.hardware }
+    /// 探索に使用した曜日（0=日曜 … 6=土曜、探索以外で作成した結果はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn weekday(&self) -> Option<u32> { self.hardware.map(|_| self.message[8] & 0xFF) }
+    /// SHA-1に入力した16ワードメッセージ
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> Vec<u32> { self.message.to_vec() }
+    /// 64bit LCG初期シード（LCGを進める前の値）
+    /// `PersonalityRNG`や`PokemonGenerator::generate_pokemon_batch_bw`の初期シードとしてそのまま使用できる
+    #[wasm_bindgen(getter)]
+    pub fn lcg_seed(&self) -> u64 { self.lcg_seed }
+    /// 周辺の起動条件のうちターゲットにも一致した数（未評価はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn robustness_hits(&self) -> Option<u32> { self.robustness.map(|(hits, _)| hits) }
+    /// 評価した周辺の起動条件の数（未評価はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn robustness_neighbours(&self) -> Option<u32> { self.robustness.map(|(_, neighbours)| neighbours) }
+    /// 頑健性スコア（周辺で一致した割合、未評価はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn robustness_score(&self) -> Option<f64> {
+        self.robustness.map(|(hits, neighbours)| if neighbours == 0 { 0.0 } else { hits as f64 / neighbours as f64 })
+    }
+    /// このヒットで決まるTID（TID/SID探索以外の結果はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn tid(&self) -> Option<u16> { self.tid_sid.map(|ids| ids.tid) }
+    /// このヒットで決まるSID（TID/SID探索以外の結果はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn sid(&self) -> Option<u16> { self.tid_sid.map(|ids| ids.sid) }
+    /// TID/SID決定までに消費した乱数回数（TID/SID探索以外の結果はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn tid_sid_advances(&self) -> Option<u32> { self.tid_sid.map(|ids| ids.advances_used) }
+
+    /// このヒットの初期シードからポケモンを連続生成する列挙器を作成
+    ///
+    /// # Arguments
+    /// * `offset` - 最初の生成までの前進数
+    /// * `count` - 生成数
+    /// * `config` - BW準拠設定
+    pub fn pokemon_enumerator(&self, offset: u64, count: u32, config: &BWGenerationConfig) -> SeedEnumerator {
+        SeedEnumerator::new(self.lcg_seed, offset, count, config)
+    }
+
+    /// 記録された探索入力からメッセージ・ハッシュ・シードを再計算し、結果と一致するか検証
+    pub fn verify(&self) -> bool {
+        let Some(profile) = self.hardware else {
+            return false;
+        };
+        let Ok(searcher) = IntegratedSeedSearcher::try_from_profile(&self.mac, &self.nazo, &profile, self.key_input) else {
+            return false;
+        };
+        let Some(weekday) = self.weekday() else {
+            return false;
+        };
+        let Ok(searcher) = searcher.with_weekday_rule(WeekdayRule::Fixed(weekday)) else {
+            return false;
+        };
+        let Ok(seconds_since_2000) = IntegratedSeedSearcher::seconds_since_2000(
+            self.year, self.month, self.date, self.hour, self.minute, self.second,
+        ) else {
+            return false;
+        };
+        let Some((time_code, date_code)) = searcher.calculate_datetime_codes(seconds_since_2000) else {
+            return false;
+        };
+
+        let params = SearchParams { timer0: self.timer0, vcount: self.vcount, key_input: self.key_input };
+        let message = searcher.build_message(&params, date_code, time_code);
+        let (h0, h1, h2, h3, h4) = calculate_pokemon_sha1(&message);
+
+        message == self.message
+            && searcher.hash_to_hex_string(h0, h1, h2, h3, h4) == self.hash
+            && calculate_pokemon_lcg_seed_from_hash(h0, h1) == self.lcg_seed
+            && calculate_pokemon_seed_from_hash(h0, h1) == self.seed
+    }
+}
+
+impl SearchResult {
+    /// このヒットの初期シードからポケモンを一括生成（ネイティブ版）
+    pub fn generate_pokemon_batch(&self, offset: u64, count: u32, config: &BWGenerationConfig) -> Vec<RawPokemonData> {
+        PokemonGenerator::generate_pokemon_batch_bw(self.lcg_seed, offset, count, config)
+    }
+
+    /// 頑健性スコアの高い順に並べ替える（未評価の結果は末尾、同点は元の順序を保つ）
+    pub fn sort_by_robustness(results: &mut [SearchResult]) {
+        results.sort_by(|a, b| {
+            let score = |result: &SearchResult| result.robustness_score().unwrap_or(-1.0);
+            score(b).total_cmp(&score(a))
+        });
+    }
+
+    /// プロファイル番号を設定（複数プロファイル探索用）
+    pub(crate) fn set_profile_index(&mut self, profile_index: u32) {
+        self.profile_index = profile_index;
+    }
 }
 
 /// 統合シード探索器
 /// 固定パラメータを事前計算し、日時範囲を高速探索する
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct IntegratedSeedSearcher {
     // 実行時に必要なパラメータ
-    hardware: String,
+    profile: HardwareProfile,
+    weekday: WeekdayRule,
+
+    // 結果の再現用に保持する入力
+    mac: [u8; 6],
+    nazo: [u32; 5],
     
     // キャッシュされた基本メッセージ
     base_message: [u32; 16],
+
+    // 探索対象のkey_input値（キー入力列挙モードでは複数）
+    key_inputs: Vec<u32>,
+
+    // ROMパラメータ表から作成した場合の既定VCount/Timer0範囲（それ以外は空）
+    rom_ranges: &'static [VCountTimer0Range],
 }
 
 #[wasm_bindgen]
@@ -100,57 +347,79 @@ impl IntegratedSeedSearcher {
     /// コンストラクタ: 固定パラメータの事前計算
     #[wasm_bindgen(constructor)]
     pub fn new(mac: &[u8], nazo: &[u32], hardware: &str, key_input: u32, frame: u32) -> Result<IntegratedSeedSearcher, JsValue> {
-        // バリデーション
-        if mac.len() != 6 {
-            return Err(JsValue::from_str("MAC address must be 6 bytes"));
-        }
-        if nazo.len() != 5 {
-            return Err(JsValue::from_str("nazo must be 5 32-bit words"));
-        }
-        
-        // Hardware type validation
-        match hardware {
-            "DS" | "DS_LITE" | "3DS" => {},
-            _ => return Err(JsValue::from_str("Hardware must be DS, DS_LITE, or 3DS")),
-        }
+        Self::try_new(mac, nazo, hardware, key_input, frame).map_err(JsValue::from)
+    }
 
-        // 基本メッセージテンプレートを事前構築（TypeScript側レイアウトに準拠）
-        let mut base_message = [0u32; 16];
-        
-        // data[0-4]: Nazo values (little-endian conversion already applied)
-        for i in 0..5 {
-            base_message[i] = swap_bytes_32(nazo[i]);
+    /// キー入力列挙モードの探索器を作成
+    /// `allowed_keys`に含まれるボタンの入力可能な全組み合わせ（未入力を含む）を探索する
+    #[wasm_bindgen]
+    pub fn with_key_enumeration(mac: &[u8], nazo: &[u32], hardware: &str, allowed_keys: u32, frame: u32) -> Result<IntegratedSeedSearcher, JsValue> {
+        Self::try_with_key_enumeration(mac, nazo, hardware, allowed_keys, frame).map_err(JsValue::from)
+    }
+
+    /// ハードウェアプロファイルから探索器を作成
+    #[wasm_bindgen]
+    pub fn from_profile(mac: &[u8], nazo: &[u32], profile: &HardwareProfile, key_input: u32) -> Result<IntegratedSeedSearcher, JsValue> {
+        Ok(Self::try_from_profile(mac, nazo, profile, key_input)?)
+    }
+
+    /// ハードウェアプロファイルからキー入力列挙モードの探索器を作成
+    #[wasm_bindgen]
+    pub fn from_profile_with_key_enumeration(mac: &[u8], nazo: &[u32], profile: &HardwareProfile, allowed_keys: u32) -> Result<IntegratedSeedSearcher, JsValue> {
+        Ok(Self::try_from_profile_with_key_enumeration(mac, nazo, profile, allowed_keys)?)
+    }
+
+    /// ROMパラメータデータベースのnazo値で探索器を作成
+    /// nazo値は本体のnazo系統に合わせて選び、既定のTimer0/VCount範囲は`default_vcount_timer0_ranges`で取得できる
+    #[wasm_bindgen]
+    pub fn from_rom(mac: &[u8], version: RomVersion, region: RomRegion, hardware: &HardwareProfile, key_input: u32) -> Result<IntegratedSeedSearcher, JsValue> {
+        Ok(Self::try_from_rom(mac, version, region, hardware, key_input)?)
+    }
+
+    /// 探索に使用するハードウェアプロファイル
+    #[wasm_bindgen(getter)]
+    pub fn hardware_profile(&self) -> HardwareProfile {
+        self.profile
+    }
+
+    /// ROMパラメータ表の既定VCount/Timer0範囲の平坦配列（`[vcount, timer0_min, timer0_max]`の繰り返し）
+    /// `from_rom`以外で作成した探索器では空配列
+    #[wasm_bindgen(getter)]
+    pub fn default_vcount_timer0_ranges(&self) -> Vec<u32> {
+        self.rom_ranges
+            .iter()
+            .flat_map(|range| [range.vcount, range.timer0_min, range.timer0_max])
+            .collect()
+    }
+
+    /// 全日の曜日を`weekday`（0=日曜 … 6=土曜）に固定
+    pub fn set_weekday_override(&mut self, weekday: u32) -> Result<(), JsValue> {
+        self.set_weekday_rule(WeekdayRule::Fixed(weekday))?;
+        Ok(())
+    }
+
+    /// 暦上の曜日を`days`日ずらす（負の値は前の曜日へずらす）
+    pub fn set_weekday_offset(&mut self, days: i32) {
+        // offsetは常に正規化済みの規則を返すため検証不要
+        self.weekday = WeekdayRule::offset(days);
+    }
+
+    /// 固定した曜日（固定していない場合はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn weekday_override(&self) -> Option<u32> {
+        match self.weekday {
+            WeekdayRule::Fixed(weekday) => Some(weekday),
+            _ => None,
         }
-        
-        // data[5]: (VCount << 16) | Timer0 - 動的に設定
-        // data[6]: MAC address lower 16 bits (no endian conversion)
-        let mac_lower = ((mac[4] as u32) << 8) | (mac[5] as u32);
-        base_message[6] = mac_lower;
-        
-        // data[7]: MAC address upper 32 bits XOR GxStat XOR Frame (little-endian conversion needed)
-        let mac_upper = (mac[0] as u32) | ((mac[1] as u32) << 8) | ((mac[2] as u32) << 16) | ((mac[3] as u32) << 24);
-        let gx_stat = 0x06000000u32;
-        let data7 = mac_upper ^ gx_stat ^ frame;
-        base_message[7] = swap_bytes_32(data7);
-        
-        // data[8]: Date (YYMMDDWW format) - 動的に設定
-        // data[9]: Time (HHMMSS00 format + PM flag) - 動的に設定
-        // data[10-11]: Fixed values 0x00000000
-        base_message[10] = 0x00000000;
-        base_message[11] = 0x00000000;
-        
-        // data[12]: Key input (now configurable)
-        base_message[12] = swap_bytes_32(key_input);
-        
-        // data[13-15]: SHA-1 padding
-        base_message[13] = 0x80000000;
-        base_message[14] = 0x00000000;
-        base_message[15] = 0x000001A0;
+    }
 
-        Ok(IntegratedSeedSearcher {
-            hardware: hardware.to_string(),
-            base_message,
-        })
+    /// 暦上の曜日に加算する日数（0～6）
+    #[wasm_bindgen(getter)]
+    pub fn weekday_offset(&self) -> u32 {
+        match self.weekday {
+            WeekdayRule::Offset(days) => days,
+            _ => 0,
+        }
     }
 
     /// 統合シード探索メイン関数
@@ -172,56 +441,12 @@ impl IntegratedSeedSearcher {
         vcount_min: u32,
         vcount_max: u32,
         target_seeds: &[u32],
-    ) -> js_sys::Array {
-        
-        let results = js_sys::Array::new();
-
-        // Target seedsをBTreeSetに変換して最適化されたルックアップを実現
-        let target_set: BTreeSet<u32> = target_seeds.iter().cloned().collect();
-
-        // 開始日時をUnix時間に変換（ループ外で1回のみ実行）
-        let start_datetime = match NaiveDate::from_ymd_opt(year_start as i32, month_start, date_start)
-            .and_then(|date| date.and_hms_opt(hour_start, minute_start, second_start)) 
-        {
-            Some(datetime) => datetime,
-            None => {
-                return results;
-            }
-        };
-        
-        let start_unix = start_datetime.and_utc().timestamp();
-        let base_seconds_since_2000 = start_unix - EPOCH_2000_UNIX;
-
-        // 外側ループ: Timer0とVCount（SIMD版と同様の構造）
-        for timer0 in timer0_min..=timer0_max {
-            for vcount in vcount_min..=vcount_max {
-                
-                // 内側ループ: 日時範囲の探索
-                for second_offset in 0..range_seconds {
-                    let current_seconds_since_2000 = base_seconds_since_2000 + second_offset as i64;
-                    
-                    let (time_code, date_code) = match self.calculate_datetime_codes(current_seconds_since_2000) {
-                        Some(result) => result,
-                        None => continue,
-                    };
-                    
-                    // メッセージを構築してSHA-1計算
-                    let message = self.build_message(timer0, vcount, date_code, time_code);
-                    let (h0, h1, h2, h3, h4) = calculate_pokemon_sha1(&message);
-                    let seed = crate::sha1::calculate_pokemon_seed_from_hash(h0, h1);
-                    
-                    // ターゲットシードマッチ時のみ日時とハッシュを生成
-                    let hash_values = HashValues { h0, h1, h2, h3, h4 };
-                    let params = SearchParams {
-                        timer0,
-                        vcount,
-                    };
-                    self.check_and_add_result(seed, &hash_values, current_seconds_since_2000, &params, &target_set, &results);
-                }
-            }
-        }
-
-        results
+    ) -> Result<js_sys::Array, JsValue> {
+        let results = self.search_seeds(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, target_seeds,
+        )?;
+        Ok(Self::to_js_array(results))
     }
 
     /// 統合シード探索SIMD版
@@ -243,205 +468,1252 @@ impl IntegratedSeedSearcher {
         vcount_min: u32,
         vcount_max: u32,
         target_seeds: &[u32],
-    ) -> js_sys::Array {
-        
-        let results = js_sys::Array::new();
-
-        // Target seedsをBTreeSetに変換して最適化されたルックアップを実現
-        let target_set: BTreeSet<u32> = target_seeds.iter().cloned().collect();
-
-        // 開始日時をUnix時間に変換（ループ外で1回のみ実行）
-        let start_datetime = match NaiveDate::from_ymd_opt(year_start as i32, month_start, date_start)
-            .and_then(|date| date.and_hms_opt(hour_start, minute_start, second_start)) 
-        {
-            Some(datetime) => datetime,
-            None => {
-                return results;
-            }
-        };
-        
-        let start_unix = start_datetime.and_utc().timestamp();
-        let base_seconds_since_2000 = start_unix - EPOCH_2000_UNIX;
-
-        // 外側ループ: Timer0とVCount
-        for timer0 in timer0_min..=timer0_max {
-            for vcount in vcount_min..=vcount_max {
-                for second_offset in (0..range_seconds).step_by(4) {
-                    let batch_size = std::cmp::min(4, range_seconds - second_offset);
-                    
-                    if batch_size == 4 {
-                        // 4つの秒を並列処理
-                        let params = SearchParams {
-                            timer0,
-                            vcount,
-                        };
-                        self.process_simd_batch(
-                            second_offset, 
-                            base_seconds_since_2000, 
-                            &params,
-                            &target_set, 
-                            &results
-                        );
-                    } else {
-                        // 残りの秒を個別処理
-                        let params = SearchParams {
-                            timer0,
-                            vcount,
-                        };
-                        self.process_remaining_seconds(
-                            second_offset, 
-                            batch_size, 
-                            base_seconds_since_2000, 
-                            &params,
-                            &target_set, 
-                            &results
-                        );
-                    }
-                }
-            }
-        }
-
-        results
+    ) -> Result<js_sys::Array, JsValue> {
+        let results = self.search_seeds_simd(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, target_seeds,
+        )?;
+        Ok(Self::to_js_array(results))
     }
 
-    /// SIMD バッチ処理（4つの秒を並列計算）
-    #[inline]
-    fn process_simd_batch(
+    /// 統合シード探索（推定確率順）
+    /// 結果をTimer0/VCountの推定出現確率の高い順に並べて返す。確率が等しい結果は`search_seeds_integrated`と同じ順序になる
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_ranked(
         &self,
-        second_offset: u32,
-        base_seconds_since_2000: i64,
-        params: &SearchParams,
-        target_seeds: &BTreeSet<u32>,
-        results: &js_sys::Array,
-    ) {
-        let mut messages = [0u32; 64]; // 4組 × 16ワード
-        let mut valid_messages = [true; 4];
-        let mut seconds_batch = [0i64; 4]; // 結果生成用
-        
-        // Timer0/VCountの値を事前に計算（SIMD用）
-        let timer_vcount_value = (params.vcount << 16) | params.timer0;
-        let swapped_timer_vcount = crate::sha1::swap_bytes_32(timer_vcount_value);
-        
-        // 4つのメッセージを準備
-        for i in 0..4 {
-            let current_second_offset = second_offset + i as u32;
-            let current_seconds_since_2000 = base_seconds_since_2000 + current_second_offset as i64;
-            
-            let (time_code, date_code) = match self.calculate_datetime_codes(current_seconds_since_2000) {
-                Some(result) => result,
-                None => {
-                    valid_messages[i] = false;
-                    continue;
-                }
-            };
-            
-            seconds_batch[i] = current_seconds_since_2000;
-            
-            // メッセージを構築してバッチに追加（バイトスワップ済み値を使用）
-            let mut message = self.base_message;
-            message[5] = swapped_timer_vcount; // 事前計算済み
-            message[8] = date_code;
-            message[9] = time_code;
-            
-            let base_idx = i * 16;
-            messages[base_idx..base_idx + 16].copy_from_slice(&message);
-        }
-        
-        // SIMD SHA-1計算を実行
-        let hash_results = crate::sha1_simd::calculate_pokemon_sha1_simd(&messages);
-        
-        // 各組の結果を処理
-        for i in 0..4 {
-            if !valid_messages[i] {
-                continue;
-            }
-            
-            let h0 = hash_results[i * 5];
-            let h1 = hash_results[i * 5 + 1];
-            let h2 = hash_results[i * 5 + 2];
-            let h3 = hash_results[i * 5 + 3];
-            let h4 = hash_results[i * 5 + 4];
-            let seed = crate::sha1::calculate_pokemon_seed_from_hash(h0, h1);
-            
-            // マッチ時のみ日時とハッシュを生成
-            let hash_values = HashValues { h0, h1, h2, h3, h4 };
-            let params_for_result = SearchParams {
-                timer0: params.timer0,
-                vcount: params.vcount,
-            };
-            self.check_and_add_result(seed, &hash_values, seconds_batch[i], &params_for_result, target_seeds, results);
-        }
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+        likelihood: &Timer0VCountLikelihood,
+    ) -> Result<js_sys::Array, JsValue> {
+        let mut results = self.search_seeds_simd(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, target_seeds,
+        )?;
+        likelihood.rank_results(&mut results);
+        Ok(Self::to_js_array(results))
     }
 
-    /// 端数秒の個別処理（非SIMD）
-    #[inline]
-    fn process_remaining_seconds(
+    /// 統合シード探索（頑健性順）
+    /// 各ヒットの周辺の起動条件を評価し、頑健性スコアの高い順に返す。周辺の一致判定にも`targets`を使う
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_robust(
         &self,
-        second_offset: u32,
-        batch_size: u32,
-        base_seconds_since_2000: i64,
-        params: &SearchParams,
-        target_seeds: &BTreeSet<u32>,
-        results: &js_sys::Array,
-    ) {
-        for i in 0..batch_size {
-            let current_second_offset = second_offset + i;
-            let current_seconds_since_2000 = base_seconds_since_2000 + current_second_offset as i64;
-            
-            let (time_code, date_code) = match self.calculate_datetime_codes(current_seconds_since_2000) {
-                Some(result) => result,
-                None => continue,
-            };
-            
-            // メッセージを構築してSHA-1計算
-            let message = self.build_message(params.timer0, params.vcount, date_code, time_code);
-            let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1(&message);
-            let seed = crate::sha1::calculate_pokemon_seed_from_hash(h0, h1);
-            
-            // マッチ時のみ日時とハッシュを生成
-            let hash_values = HashValues { h0, h1, h2, h3, h4 };
-            self.check_and_add_result(seed, &hash_values, current_seconds_since_2000, params, target_seeds, results);
-        }
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+        window: &RobustnessWindow,
+    ) -> Result<js_sys::Array, JsValue> {
+        let mut results = self.search_seeds_with_targets(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, targets,
+        )?;
+        self.score_robustness(&mut results, targets, window);
+        SearchResult::sort_by_robustness(&mut results);
+        Ok(Self::to_js_array(results))
     }
 
-    /// 日時コード生成（結果表示用日時は遅延生成）
-    #[inline(always)]
-    fn calculate_datetime_codes(&self, seconds_since_2000: i64) -> Option<(u32, u32)> {
-        if seconds_since_2000 < 0 {
-            return None;
-        }
-        
-        let time_index = (seconds_since_2000 % 86400) as u32;
-        let date_index = (seconds_since_2000 / 86400) as u32;
-
-        let time_code = TimeCodeGenerator::get_time_code_for_hardware(time_index, &self.hardware);
-        let date_code = DateCodeGenerator::get_date_code(date_index);
-        
-        Some((time_code, date_code))
+    /// TID/SID探索
+    /// 各起動条件の初期シードから`mode`の「始めから」で決まるTID/SIDを計算し、`target`を満たすものを返す
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_tid_sid_integrated(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        mode: GameMode,
+        target: &TidSidTarget,
+    ) -> Result<js_sys::Array, JsValue> {
+        let results = self.search_tid_sid(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, mode, target,
+        )?;
+        Ok(Self::to_js_array(results))
     }
 
-    /// 結果表示用の日時を生成（マッチした場合のみ）
-    fn generate_display_datetime(&self, seconds_since_2000: i64) -> Option<(u32, u32, u32, u32, u32, u32)> {
-        let result_datetime = chrono::DateTime::from_timestamp(seconds_since_2000 + EPOCH_2000_UNIX, 0)?
-            .naive_utc();
-        
-        Some((
-            result_datetime.year() as u32,
-            result_datetime.month(),
-            result_datetime.day(),
-            result_datetime.hour(),
-            result_datetime.minute(),
-            result_datetime.second()
-        ))
-    }
+    /// 初期シード列挙（ターゲット指定なし）
+    /// 全候補のシードをTimer0 → VCount → キー入力 → 秒の順に詰めたUint32Arrayで返す
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn list_seeds_integrated(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<Vec<u32>, JsValue> {
+        Ok(self.list_seeds(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?)
+    }
+
+    /// 日次時間帯探索
+    /// `daily_windows`は0時からの経過秒による[開始, 終了)の組を平坦に並べた配列
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_daily_windows_integrated(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        year_end: u32,
+        month_end: u32,
+        date_end: u32,
+        daily_windows: &[u32],
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+    ) -> Result<js_sys::Array, JsValue> {
+        if !daily_windows.len().is_multiple_of(2) {
+            return Err(InitSeedError::UnpairedDailyWindow.into());
+        }
+        let windows: Vec<(u32, u32)> = daily_windows.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect();
+        let results = self.search_seeds_daily_windows(
+            year_start, month_start, date_start, year_end, month_end, date_end,
+            &windows, timer0_min, timer0_max, vcount_min, vcount_max, target_seeds,
+        )?;
+        Ok(Self::to_js_array(results))
+    }
+
+    /// 照合条件を指定した統合シード探索
+    /// `TargetMatcher`で64bit初期シードやマスク付きの条件を指定する
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_with_targets(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+    ) -> Result<js_sys::Array, JsValue> {
+        let results = self.search_seeds_with_targets(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, targets,
+        )?;
+        Ok(Self::to_js_array(results))
+    }
+
+    /// 中断可能な統合シード探索
+    /// `progress_callback`は探索単位ごとに(completed_units, total_units, results_found, cursor)で呼び出される
+    /// （completed_units・total_unitsはBigInt）。
+    /// `cursor`を保存しておけば`resume_search_integrated`で続きから再開できる。
+    /// コールバック内で`token.cancel()`を呼ぶと次の探索単位の前で中断する
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_cancellable(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+        token: &CancellationToken,
+        progress_callback: Option<js_sys::Function>,
+    ) -> Result<SearchOutcome, JsValue> {
+        Ok(self.search_seeds_cancellable(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, targets, token,
+            |progress| Self::notify_progress(progress_callback.as_ref(), progress),
+        )?)
+    }
+
+    /// チェックポイントから探索を再開
+    /// 探索範囲はカーソルに保存されたものを使用する。
+    /// 探索器の設定（MAC・nazo・ハードウェア・フレーム・キー入力）が保存時と異なる場合はエラー
+    #[wasm_bindgen]
+    pub fn resume_search_integrated(
+        &self,
+        cursor: &SearchCursor,
+        targets: &TargetMatcher,
+        token: &CancellationToken,
+        progress_callback: Option<js_sys::Function>,
+    ) -> Result<SearchOutcome, JsValue> {
+        self.resume_search(cursor, targets, token, |progress| {
+            Self::notify_progress(progress_callback.as_ref(), progress)
+        })
+        .map_err(JsValue::from)
+    }
+
+    /// 固定長レコード形式の統合シード探索
+    /// ヒットを`SearchResult`オブジェクトにせず、`ResultRecordLayout`のレイアウトで`Uint32Array`へ書き出す。
+    /// 結果の順序は`search_seeds_integrated`と同一
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_records(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<u32>, JsValue> {
+        Ok(self.search_seed_records(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, targets,
+        )?)
+    }
+
+    /// 固定長レコードを`SearchResult`に展開
+    /// 表示する分だけを必要に応じて展開する用途を想定。レコードはこの探索器で生成したものに限る
+    #[wasm_bindgen]
+    pub fn decode_result_records(&self, records: &[u32]) -> Result<js_sys::Array, JsValue> {
+        self.try_decode_result_records(records)
+            .map(Self::to_js_array)
+            .map_err(JsValue::from)
+    }
+
+    /// 探索器設定の指紋（チェックポイントの照合用）
+    #[wasm_bindgen(getter)]
+    pub fn config_fingerprint(&self) -> u64 {
+        let message_bytes = self.base_message.iter().flat_map(|word| word.to_le_bytes());
+        let key_bytes = self.key_inputs.iter().flat_map(|key| key.to_le_bytes());
+        // 暦どおりの曜日では何も追加せず、既存のチェックポイントの指紋を変えない
+        let weekday_bytes: Vec<u8> = match self.weekday {
+            WeekdayRule::Calendar => Vec::new(),
+            WeekdayRule::Fixed(weekday) => vec![b'F', weekday as u8],
+            WeekdayRule::Offset(days) => vec![b'O', days as u8],
+        };
+        fnv1a_64(
+            self.profile.hardware().name().bytes()
+                .chain([0, self.profile.pm_flag() as u8])
+                .chain(message_bytes)
+                .chain((self.key_inputs.len() as u32).to_le_bytes())
+                .chain(key_bytes)
+                .chain(weekday_bytes),
+        )
+    }
+}
+
+/// ネイティブRust向けAPI（js_sys非依存）
+/// wasm_bindgen公開メソッドはこれらの薄いラッパーとして実装する
+impl IntegratedSeedSearcher {
+    /// コンストラクタ（ネイティブ版）
+    /// `hardware`は種別名で、`frame`はそのプロファイルのフレーム値を上書きする
+    pub fn try_new(mac: &[u8], nazo: &[u32], hardware: &str, key_input: u32, frame: u32) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        let profile = HardwareProfile::parse(hardware)?.with_frame(frame);
+        Self::try_from_profile(mac, nazo, &profile, key_input)
+    }
+
+    /// ハードウェアプロファイルから探索器を作成（ネイティブ版）
+    pub fn try_from_profile(mac: &[u8], nazo: &[u32], profile: &HardwareProfile, key_input: u32) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        // バリデーション
+        if mac.len() != 6 {
+            return Err(InitSeedError::InvalidMacLength(mac.len()));
+        }
+        if nazo.len() != 5 {
+            return Err(InitSeedError::InvalidNazoLength(nazo.len()));
+        }
+
+        // 基本メッセージテンプレートを事前構築（TypeScript側レイアウトに準拠）
+        let mut base_message = [0u32; 16];
+        
+        // data[0-4]: Nazo values (little-endian conversion already applied)
+        for i in 0..5 {
+            base_message[i] = swap_bytes_32(nazo[i]);
+        }
+        
+        // data[5]: (VCount << 16) | Timer0 - 動的に設定
+        // data[6]: MAC address lower 16 bits (no endian conversion)
+        let mac_lower = ((mac[4] as u32) << 8) | (mac[5] as u32);
+        base_message[6] = mac_lower;
+        
+        // data[7]: MAC address upper 32 bits XOR GxStat XOR Frame (little-endian conversion needed)
+        let mac_upper = (mac[0] as u32) | ((mac[1] as u32) << 8) | ((mac[2] as u32) << 16) | ((mac[3] as u32) << 24);
+        let data7 = mac_upper ^ profile.data7_mask()?;
+        base_message[7] = swap_bytes_32(data7);
+        
+        // data[8]: Date (YYMMDDWW format) - 動的に設定
+        // data[9]: Time (HHMMSS00 format + PM flag) - 動的に設定
+        // data[10-11]: Fixed values 0x00000000
+        base_message[10] = 0x00000000;
+        base_message[11] = 0x00000000;
+        
+        // data[12]: Key input (now configurable)
+        base_message[12] = swap_bytes_32(key_input);
+        
+        // data[13-15]: SHA-1 padding
+        base_message[13] = 0x80000000;
+        base_message[14] = 0x00000000;
+        base_message[15] = 0x000001A0;
+
+        let mut mac_bytes = [0u8; 6];
+        mac_bytes.copy_from_slice(mac);
+        let mut nazo_words = [0u32; 5];
+        nazo_words.copy_from_slice(nazo);
+
+        Ok(IntegratedSeedSearcher {
+            profile: *profile,
+            weekday: WeekdayRule::Calendar,
+            mac: mac_bytes,
+            nazo: nazo_words,
+            base_message,
+            key_inputs: vec![key_input],
+            rom_ranges: &[],
+        })
+    }
+
+    /// キー入力列挙モードの探索器を作成（ネイティブ版）
+    pub fn try_with_key_enumeration(mac: &[u8], nazo: &[u32], hardware: &str, allowed_keys: u32, frame: u32) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        let profile = HardwareProfile::parse(hardware)?.with_frame(frame);
+        Self::try_from_profile_with_key_enumeration(mac, nazo, &profile, allowed_keys)
+    }
+
+    /// ROMパラメータデータベースのnazo値で探索器を作成（ネイティブ版）
+    /// 本体のnazo系統に対応する値がないROMでは`UnsupportedRomHardware`を返す
+    pub fn try_from_rom(mac: &[u8], version: RomVersion, region: RomRegion, hardware: &HardwareProfile, key_input: u32) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        let rom = RomParameters::for_hardware(version, region, hardware)?;
+        let mut searcher = Self::try_from_profile(mac, rom.nazo_words(), hardware, key_input)?;
+        searcher.rom_ranges = rom.ranges();
+        Ok(searcher)
+    }
+
+    /// ROMパラメータ表の既定VCount/Timer0範囲（`try_from_rom`以外で作成した探索器では空）
+    pub fn default_ranges(&self) -> &'static [VCountTimer0Range] {
+        self.rom_ranges
+    }
+
+    /// ハードウェアプロファイルからキー入力列挙モードの探索器を作成（ネイティブ版）
+    pub fn try_from_profile_with_key_enumeration(mac: &[u8], nazo: &[u32], profile: &HardwareProfile, allowed_keys: u32) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        if allowed_keys & !KEY_BUTTONS_ALL != 0 {
+            return Err(InitSeedError::InvalidKeyMask(allowed_keys));
+        }
+
+        let mut searcher = Self::try_from_profile(mac, nazo, profile, KEY_INPUT_NONE)?;
+        searcher.key_inputs = KeyInputEnumerator::enumerate(allowed_keys);
+        Ok(searcher)
+    }
+
+    /// 曜日の決め方を指定した探索器（ネイティブ版）
+    /// 固定する曜日が0～6の範囲外ならエラー
+    pub fn with_weekday_rule(mut self, rule: WeekdayRule) -> Result<IntegratedSeedSearcher, InitSeedError> {
+        self.set_weekday_rule(rule)?;
+        Ok(self)
+    }
+
+    /// 曜日の決め方を検証・正規化して変更
+    pub(crate) fn set_weekday_rule(&mut self, rule: WeekdayRule) -> Result<(), InitSeedError> {
+        self.weekday = rule.validated()?;
+        Ok(())
+    }
+
+    /// 探索に使用する曜日の決め方
+    pub fn weekday_rule(&self) -> WeekdayRule {
+        self.weekday
+    }
+
+    /// 探索対象のkey_input値一覧
+    pub fn key_inputs(&self) -> &[u32] {
+        &self.key_inputs
+    }
+
+    /// 同じ本体・ROM設定で、探索対象のkey_inputだけを差し替えた探索器
+    pub(crate) fn with_key_input(&self, key_input: u32) -> IntegratedSeedSearcher {
+        let mut base_message = self.base_message;
+        base_message[12] = swap_bytes_32(key_input);
+        IntegratedSeedSearcher {
+            profile: self.profile,
+            weekday: self.weekday,
+            mac: self.mac,
+            nazo: self.nazo,
+            base_message,
+            key_inputs: vec![key_input],
+            rom_ranges: self.rom_ranges,
+        }
+    }
+
+    /// 統合シード探索（ネイティブ版）
+    /// 結果はTimer0 → VCount → 秒の順に並ぶ
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        let targets = TargetMatcher::from_seeds(target_seeds);
+
+        let mut results = Vec::new();
+        self.for_each_hash(&range, &mut |params, seconds_since_2000, hash_values| {
+            self.check_and_add_result(hash_values, seconds_since_2000, params, &targets, &mut results);
+        });
+
+        Ok(results)
+    }
+
+    /// 統合シード探索SIMD版（ネイティブ版）
+    /// 結果の順序は`search_seeds`と同一
+    #[allow(clippy::too_many_arguments)]  // SIMD search function requires comprehensive parameters
+    pub fn search_seeds_simd(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        self.search_seeds_with_targets(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+            &TargetMatcher::from_seeds(target_seeds),
+        )
+    }
+
+    /// 照合条件を指定した統合シード探索（ネイティブ版、SIMD使用）
+    /// 64bit初期シードやマスク付きの条件で探索できる。結果の順序は`search_seeds`と同一
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_with_targets(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+
+        let mut results = Vec::new();
+        self.for_each_hash_simd(&range, &mut |params, seconds_since_2000, hash_values| {
+            self.check_and_add_result(hash_values, seconds_since_2000, params, targets, &mut results);
+        });
+
+        Ok(results)
+    }
+
+    /// 固定長レコード形式の統合シード探索（ネイティブ版、SIMD使用）
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seed_records(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<u32>, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+
+        let mut records = Vec::new();
+        self.for_each_hash_simd(&range, &mut |params, seconds_since_2000, hash_values| {
+            self.check_and_add_record(hash_values, seconds_since_2000, params, 0, targets, &mut records);
+        });
+
+        Ok(records)
+    }
+
+    /// 固定長レコードを`SearchResult`に展開（ネイティブ版）
+    pub fn try_decode_result_records(&self, records: &[u32]) -> Result<Vec<SearchResult>, InitSeedError> {
+        ResultRecord::decode_all(records)?
+            .iter()
+            .map(|record| self.expand_record(record))
+            .collect()
+    }
+
+    /// 各結果の周辺の起動条件を評価し、頑健性スコアを設定（ネイティブ版）
+    /// 日時コード表の範囲外になる周辺は評価対象に含めない
+    pub fn score_robustness(&self, results: &mut [SearchResult], targets: &TargetMatcher, window: &RobustnessWindow) {
+        for result in results.iter_mut() {
+            let Ok(seconds_since_2000) = Self::seconds_since_2000(
+                result.year, result.month, result.date, result.hour, result.minute, result.second,
+            ) else {
+                continue;
+            };
+            let params = SearchParams { timer0: result.timer0, vcount: result.vcount, key_input: result.key_input };
+
+            let (mut hits, mut neighbours) = (0, 0);
+            for (seconds, neighbour) in window.neighbours(seconds_since_2000, params) {
+                if let Some(matched) = self.matches_at(seconds, &neighbour, targets) {
+                    neighbours += 1;
+                    hits += matched as u32;
+                }
+            }
+            result.robustness = Some((hits, neighbours));
+        }
+    }
+
+    /// TID/SID探索（ネイティブ版、SIMD使用）
+    /// 結果の順序は`search_seeds`と同一。「続きから」のモードはTID/SIDが決まらないためエラー。
+    /// 条件のないターゲットは全候補に一致し結果が際限なく増えるためエラー
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_tid_sid(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        mode: GameMode,
+        target: &TidSidTarget,
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        if matches!(mode, GameMode::BwContinue | GameMode::Bw2ContinueWithMemoryLink | GameMode::Bw2ContinueNoMemoryLink) {
+            return Err(InitSeedError::NoTidSidForMode(mode as u32));
+        }
+        if target.is_empty() {
+            return Err(InitSeedError::EmptyTidSidTarget);
+        }
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+
+        let mut results = Vec::new();
+        self.for_each_hash_simd(&range, &mut |params, seconds_since_2000, hash_values| {
+            let lcg_seed = calculate_pokemon_lcg_seed_from_hash(hash_values.h0, hash_values.h1);
+            let ids = calculate_tid_sid_from_seed(lcg_seed, mode);
+            if !target.matches(ids.tid, ids.sid) {
+                return;
+            }
+            let seed = (PersonalityRNG::next_seed(lcg_seed) >> 32) as u32;
+            if let Some(mut result) = self.build_result(seed, lcg_seed, hash_values, seconds_since_2000, params) {
+                result.tid_sid = Some(ids);
+                results.push(result);
+            }
+        });
+
+        Ok(results)
+    }
+
+    /// 1つの起動条件がターゲットに一致するか（日時コード表の範囲外はNone）
+    fn matches_at(&self, seconds_since_2000: i64, params: &SearchParams, targets: &TargetMatcher) -> Option<bool> {
+        let (time_code, date_code) = self.calculate_datetime_codes(seconds_since_2000)?;
+        let (h0, h1, h2, h3, h4) = calculate_pokemon_sha1(&self.build_message(params, date_code, time_code));
+        Some(Self::match_targets(&HashValues { h0, h1, h2, h3, h4 }, targets).is_some())
+    }
+
+    /// 1レコードを再現用の入力とメッセージを含む`SearchResult`に展開
+    pub(crate) fn expand_record(&self, record: &ResultRecord) -> Result<SearchResult, InitSeedError> {
+        let [h0, h1, h2, h3, h4] = record.hash;
+        let hash_values = HashValues { h0, h1, h2, h3, h4 };
+        let params = SearchParams { timer0: record.timer0, vcount: record.vcount, key_input: record.key_input };
+
+        let mut result = self
+            .build_result(record.seed, record.lcg_seed, &hash_values, record.seconds_since_2000 as i64, &params)
+            .ok_or(InitSeedError::DateOutOfRange)?;
+        result.set_profile_index(record.profile_index);
+        Ok(result)
+    }
+
+    /// 中断可能な統合シード探索（ネイティブ版、SIMD使用）
+    /// 探索単位（Timer0・VCount・キー入力・日付の組）を処理するたびに`on_progress`を呼び出し、
+    /// 各単位の開始前に`token`を確認する。キャンセル時は途中結果と中断位置を返す（キャンセルはエラーではない）
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_cancellable<P>(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+        token: &CancellationToken,
+        mut on_progress: P,
+    ) -> Result<SearchOutcome, InitSeedError>
+    where
+        P: FnMut(&SearchProgress),
+    {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        self.search_range_cancellable(&range, 0, targets, token, &mut on_progress)
+    }
+
+    /// チェックポイントから探索を再開（ネイティブ版）
+    /// カーソルより前の探索単位は処理済みとして読み飛ばす。
+    /// 進捗と結果の件数は再開後の分のみを数える（`completed_units`は通算）
+    pub fn resume_search<P>(
+        &self,
+        cursor: &SearchCursor,
+        targets: &TargetMatcher,
+        token: &CancellationToken,
+        mut on_progress: P,
+    ) -> Result<SearchOutcome, InitSeedError>
+    where
+        P: FnMut(&SearchProgress),
+    {
+        if cursor.fingerprint() != self.config_fingerprint() {
+            return Err(InitSeedError::CheckpointMismatch);
+        }
+
+        let range = cursor.range();
+        range.validate().map_err(|_| InitSeedError::InvalidCheckpoint)?;
+        if cursor.next_unit() > self.total_units(&range)? {
+            return Err(InitSeedError::InvalidCheckpoint);
+        }
+
+        self.search_range_cancellable(&range, cursor.next_unit(), targets, token, &mut on_progress)
+    }
+
+    /// 探索範囲内の探索単位の総数
+    /// u64に収まらない場合は`TooManySearchUnits`を返す
+    fn total_units(&self, range: &SearchRange) -> Result<u64, InitSeedError> {
+        let day_count = Self::day_segments(range.base_seconds_since_2000, range.range_seconds).count() as u64;
+        [
+            (range.timer0_max - range.timer0_min) as u64 + 1,
+            (range.vcount_max - range.vcount_min) as u64 + 1,
+            self.key_inputs.len() as u64,
+            day_count,
+        ]
+        .into_iter()
+        .try_fold(1u64, |total, count| total.checked_mul(count))
+        .ok_or(InitSeedError::TooManySearchUnits)
+    }
+
+    /// 中断可能な探索の本体
+    /// 番号が`start_unit`未満の探索単位は処理済みとして読み飛ばす
+    pub(crate) fn search_range_cancellable<P>(
+        &self,
+        range: &SearchRange,
+        start_unit: u64,
+        targets: &TargetMatcher,
+        token: &CancellationToken,
+        on_progress: &mut P,
+    ) -> Result<SearchOutcome, InitSeedError>
+    where
+        P: FnMut(&SearchProgress),
+    {
+        let fingerprint = self.config_fingerprint();
+        let segments: Vec<(i64, u32)> = Self::day_segments(range.base_seconds_since_2000, range.range_seconds).collect();
+        let total_units = self.total_units(range)?;
+
+        let mut results = Vec::new();
+        let mut completed_units = 0;
+        for timer0 in range.timer0_min..=range.timer0_max {
+            for vcount in range.vcount_min..=range.vcount_max {
+                for &key_input in &self.key_inputs {
+                    let params = SearchParams { timer0, vcount, key_input };
+                    for &(segment_start, segment_seconds) in &segments {
+                        if completed_units < start_unit {
+                            completed_units += 1;
+                            continue;
+                        }
+
+                        if token.is_cancelled() {
+                            let position = SearchPosition { timer0, vcount, key_input, seconds_since_2000: segment_start };
+                            let cursor = SearchCursor::new(fingerprint, range, completed_units);
+                            return Ok(SearchOutcome::new(results, completed_units, total_units, Some((position, cursor))));
+                        }
+
+                        self.process_segment_simd(&params, segment_start, segment_seconds, &mut |params, seconds_since_2000, hash_values| {
+                            self.check_and_add_result(hash_values, seconds_since_2000, params, targets, &mut results);
+                        });
+
+                        completed_units += 1;
+                        on_progress(&SearchProgress {
+                            completed_units,
+                            total_units,
+                            results_found: results.len() as u32,
+                            cursor: SearchCursor::new(fingerprint, range, completed_units),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(SearchOutcome::new(results, completed_units, total_units, None))
+    }
+
+    /// `list_seeds`で1つの配列に詰められる候補数の上限（`Vec<u32>`の最大長）
+    const MAX_LISTED_SEEDS: u64 = (isize::MAX as usize / std::mem::size_of::<u32>()) as u64;
+    /// `list_seeds`で事前確保する要素数の上限（これを超える分は列挙しながら伸長する）
+    const LIST_SEEDS_RESERVE_LIMIT: u64 = 1 << 24;
+
+    /// 全候補の初期シードを列挙（ネイティブ版、ターゲット指定なし）
+    /// 候補数が配列に収まらない場合は`SeedListTooLarge`を返す。
+    /// 結果はTimer0 → VCount → キー入力 → 秒の順に詰めた配列で、各要素の位置は
+    /// `((timer0 - timer0_min) * VCount数 + (vcount - vcount_min)) * キー入力数 + キー入力番号) * range_seconds + 秒オフセット`
+    /// となる
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn list_seeds(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<Vec<u32>, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        // wasm32ではusizeの積がすぐ溢れるため、u64で数えて配列の上限と比較する
+        let candidate_count = [
+            (timer0_max - timer0_min) as u64 + 1,
+            (vcount_max - vcount_min) as u64 + 1,
+            self.key_inputs.len() as u64,
+            range_seconds as u64,
+        ]
+        .into_iter()
+        .try_fold(1u64, |total, count| total.checked_mul(count))
+        .filter(|&count| count <= Self::MAX_LISTED_SEEDS)
+        .ok_or(InitSeedError::SeedListTooLarge)?;
+        let mut seeds = Vec::with_capacity(candidate_count.min(Self::LIST_SEEDS_RESERVE_LIMIT) as usize);
+
+        self.for_each_hash_simd(&range, &mut |_params, _seconds_since_2000, hash_values| {
+            seeds.push(calculate_pokemon_seed_from_hash(hash_values.h0, hash_values.h1));
+        });
+
+        Ok(seeds)
+    }
+
+    /// 日次時間帯探索（ネイティブ版）
+    /// 開始日から終了日（両端を含む）までの各日について、`daily_windows`で指定した時間帯の秒のみを探索する。
+    /// 時間帯は0時からの経過秒による半開区間`[開始, 終了)`で、重複・隣接する時間帯は統合される。
+    /// 結果はTimer0 → VCount → キー入力 → 日時の順に並ぶ
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_daily_windows(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        year_end: u32,
+        month_end: u32,
+        date_end: u32,
+        daily_windows: &[(u32, u32)],
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        self.search_seeds_daily_windows_with_targets(
+            year_start, month_start, date_start, year_end, month_end, date_end,
+            daily_windows, timer0_min, timer0_max, vcount_min, vcount_max, &TargetMatcher::from_seeds(target_seeds),
+        )
+    }
+
+    /// 照合条件を指定した日次時間帯探索（ネイティブ版）
+    /// 結果の順序は`search_seeds_daily_windows`と同一
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_daily_windows_with_targets(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        year_end: u32,
+        month_end: u32,
+        date_end: u32,
+        daily_windows: &[(u32, u32)],
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        let windows = Self::normalize_daily_windows(daily_windows)?;
+        SearchRange::check_bounds(timer0_min, timer0_max, vcount_min, vcount_max)?;
+        let (first_day_start, last_day_start) = Self::daily_span(year_start, month_start, date_start, year_end, month_end, date_end)?;
+
+        let mut results = Vec::new();
+        for timer0 in timer0_min..=timer0_max {
+            for vcount in vcount_min..=vcount_max {
+                for &key_input in &self.key_inputs {
+                    let params = SearchParams { timer0, vcount, key_input };
+                    for day_start in (first_day_start..=last_day_start).step_by(86400) {
+                        for &(window_start, window_end) in &windows {
+                            self.process_segment_simd(&params, day_start + window_start as i64, window_end - window_start, &mut |params, seconds_since_2000, hash_values| {
+                                self.check_and_add_result(hash_values, seconds_since_2000, params, targets, &mut results);
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// 日次時間帯探索の初日と最終日の0時（2000年1月1日からの経過秒）を検証して返す
+    pub(crate) fn daily_span(
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        year_end: u32,
+        month_end: u32,
+        date_end: u32,
+    ) -> Result<(i64, i64), InitSeedError> {
+        let first_day_start = Self::seconds_since_2000(year_start, month_start, date_start, 0, 0, 0)?;
+        let last_day_start = Self::seconds_since_2000(year_end, month_end, date_end, 0, 0, 0)?;
+        if last_day_start < first_day_start {
+            return Err(InitSeedError::ReversedDateRange);
+        }
+        // 日数が多いとu32の秒数に収まらないため、i64のまま終端を検証する
+        SearchRange::check_span(first_day_start, last_day_start + 86400)?;
+        Ok((first_day_start, last_day_start))
+    }
+
+    /// 日次時間帯を検証し、開始秒順に整列・統合する
+    pub(crate) fn normalize_daily_windows(daily_windows: &[(u32, u32)]) -> Result<Vec<(u32, u32)>, InitSeedError> {
+        let mut windows = daily_windows.to_vec();
+        if let Some(&(start, end)) = windows.iter().find(|&&(start, end)| start >= end || end > 86400) {
+            return Err(InitSeedError::InvalidDailyWindow { start, end });
+        }
+        windows.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(windows.len());
+        for (start, end) in windows {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Ok(merged)
+    }
+}
+
+/// ネイティブ並列探索（wasm32以外）
+/// Timer0 × VCount × 秒チャンクをワークアイテムに分割し、全CPUコアで処理する
+#[cfg(not(target_arch = "wasm32"))]
+impl IntegratedSeedSearcher {
+    /// 1ワークアイテムが担当する秒数（SIMDバッチ幅の倍数、1日単位）
+    const PARALLEL_CHUNK_SECONDS: u32 = 86400;
+
+    /// 統合シード探索（マルチスレッド版）
+    /// 結果の順序は`search_seeds`と同一。`thread_count`が0の場合は利用可能なコア数を使用
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_parallel(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        target_seeds: &[u32],
+        thread_count: usize,
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        Ok(self.search_range_parallel(&range, &TargetMatcher::from_seeds(target_seeds), thread_count, Self::PARALLEL_CHUNK_SECONDS))
+    }
+
+    /// 並列探索の本体（チャンク幅を指定可能）
+    pub(crate) fn search_range_parallel(
+        &self,
+        range: &SearchRange,
+        targets: &TargetMatcher,
+        thread_count: usize,
+        chunk_seconds: u32,
+    ) -> Vec<SearchResult> {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::Mutex;
+
+        let chunk_seconds = chunk_seconds.max(SIMD_BATCH_SIZE) / SIMD_BATCH_SIZE * SIMD_BATCH_SIZE;
+
+        // ワークアイテムは単一スレッド版と同じ順序（Timer0 → VCount → キー入力 → 秒）の通し番号で表し、
+        // 各スレッドが番号から(パラメータ, チャンク)を復元する
+        // キー入力をチャンクの外側に置かないと、複数キー・複数チャンクで結果順が崩れる
+        let span = |min: u32, max: u32| if min <= max { (max - min) as u64 + 1 } else { 0 };
+        let vcount_count = span(range.vcount_min, range.vcount_max);
+        let key_count = self.key_inputs.len() as u64;
+        let chunk_count = range.range_seconds.div_ceil(chunk_seconds) as u64;
+        let item_count = span(range.timer0_min, range.timer0_max) * vcount_count * key_count * chunk_count;
+        if item_count == 0 {
+            return Vec::new();
+        }
+
+        let decode = |index: u64| {
+            let chunk = index % chunk_count;
+            let key = (index / chunk_count) % key_count;
+            let vcount = (index / chunk_count / key_count) % vcount_count;
+            let timer0 = index / chunk_count / key_count / vcount_count;
+            let chunk_start = chunk as u32 * chunk_seconds;
+            let params = SearchParams {
+                timer0: range.timer0_min + timer0 as u32,
+                vcount: range.vcount_min + vcount as u32,
+                key_input: self.key_inputs[key as usize],
+            };
+            let chunk_range = std::cmp::min(chunk_seconds, range.range_seconds - chunk_start);
+            (params, range.base_seconds_since_2000 + chunk_start as i64, chunk_range)
+        };
+
+        let thread_count = match thread_count {
+            0 => std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            n => n,
+        }
+        .min(usize::try_from(item_count).unwrap_or(usize::MAX));
+
+        // 結果はスレッドごとに(通し番号, 結果)で集め、最後に番号順に並べて連結する
+        let collected: Mutex<Vec<(u64, Vec<SearchResult>)>> = Mutex::new(Vec::new());
+        let next_item = AtomicU64::new(0);
+
+        std::thread::scope(|scope| {
+            for _ in 0..thread_count {
+                scope.spawn(|| {
+                    let mut thread_results = Vec::new();
+                    loop {
+                        let index = next_item.fetch_add(1, Ordering::Relaxed);
+                        if index >= item_count {
+                            break;
+                        }
+                        let (params, chunk_base, chunk_range) = decode(index);
+                        let mut local_results = Vec::new();
+                        let mut visitor = |params: &SearchParams, seconds_since_2000: i64, hash_values: &HashValues| {
+                            self.check_and_add_result(hash_values, seconds_since_2000, params, targets, &mut local_results);
+                        };
+                        for (segment_start, segment_seconds) in Self::day_segments(chunk_base, chunk_range) {
+                            self.process_segment_simd(&params, segment_start, segment_seconds, &mut visitor);
+                        }
+                        if !local_results.is_empty() {
+                            thread_results.push((index, local_results));
+                        }
+                    }
+                    collected.lock().unwrap().extend(thread_results);
+                });
+            }
+        });
+
+        let mut collected = collected.into_inner().unwrap();
+        collected.sort_unstable_by_key(|&(index, _)| index);
+        collected.into_iter().flat_map(|(_, results)| results).collect()
+    }
+}
+
+impl IntegratedSeedSearcher {
+    /// 結果配列をJS配列へ変換
+    pub(crate) fn to_js_array(results: Vec<SearchResult>) -> js_sys::Array {
+        results.into_iter().map(JsValue::from).collect()
+    }
+
+    /// 進捗をJSコールバックへ通知
+    /// 引数は(completed_units, total_units, results_found, cursor)で、単位数はBigIntで渡す
+    fn notify_progress(callback: Option<&js_sys::Function>, progress: &SearchProgress) {
+        if let Some(callback) = callback {
+            let args = js_sys::Array::of4(
+                &JsValue::from(progress.completed_units),
+                &JsValue::from(progress.total_units),
+                &JsValue::from(progress.results_found),
+                &JsValue::from(progress.cursor),
+            );
+            // コールバック内の例外は探索を止めない
+            let _ = callback.apply(&JsValue::NULL, &args);
+        }
+    }
+
+    /// 開始日時を2000年1月1日からの経過秒に変換（ループ外で1回のみ実行）
+    /// 存在しない日付・時刻はエラー。2000年より前は負の値となる（範囲の検証は`SearchRange`で行う）
+    pub(crate) fn seconds_since_2000(year: u32, month: u32, date: u32, hour: u32, minute: u32, second: u32) -> Result<i64, InitSeedError> {
+        let start_datetime = NaiveDate::from_ymd_opt(year as i32, month, date)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .ok_or(InitSeedError::InvalidDate { year, month, day: date, hour, minute, second })?;
+        Ok(start_datetime.and_utc().timestamp() - EPOCH_2000_UNIX)
+    }
+
+    /// 探索範囲の全候補についてSHA-1を計算し、visitorへ渡す（スカラー版）
+    #[inline(never)]  // 大きな関数はinlineしない
+    fn for_each_hash<F>(&self, range: &SearchRange, visitor: &mut F)
+    where
+        F: FnMut(&SearchParams, i64, &HashValues),
+    {
+        // 外側ループ: Timer0、VCount、キー入力（SIMD版と同様の構造）
+        for timer0 in range.timer0_min..=range.timer0_max {
+            for vcount in range.vcount_min..=range.vcount_max {
+                for &key_input in &self.key_inputs {
+                    let params = SearchParams { timer0, vcount, key_input };
+                    for (segment_start, segment_seconds) in Self::day_segments(range.base_seconds_since_2000, range.range_seconds) {
+                        // 日付内ではdata[9]以外が固定なので中間状態を1回だけ計算
+                        let prefix = self.build_prefix(&params, segment_start);
+                        self.process_remaining_seconds(&prefix, segment_start, segment_seconds, &params, visitor);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 探索範囲の全候補についてSHA-1を計算し、visitorへ渡す（SIMD版）
+    #[inline(never)]  // 大きな関数はinlineしない
+    fn for_each_hash_simd<F>(&self, range: &SearchRange, visitor: &mut F)
+    where
+        F: FnMut(&SearchParams, i64, &HashValues),
+    {
+        // 外側ループ: Timer0、VCount、キー入力
+        for timer0 in range.timer0_min..=range.timer0_max {
+            for vcount in range.vcount_min..=range.vcount_max {
+                for &key_input in &self.key_inputs {
+                    let params = SearchParams { timer0, vcount, key_input };
+                    for (segment_start, segment_seconds) in Self::day_segments(range.base_seconds_since_2000, range.range_seconds) {
+                        self.process_segment_simd(&params, segment_start, segment_seconds, visitor);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 同一日付内の連続した秒についてSHA-1を計算し、visitorへ渡す（SIMD版）
+    #[inline]
+    pub(crate) fn process_segment_simd<F>(&self, params: &SearchParams, segment_start: i64, segment_seconds: u32, visitor: &mut F)
+    where
+        F: FnMut(&SearchParams, i64, &HashValues),
+    {
+        // 日付内ではdata[9]以外が固定なので中間状態を1回だけ計算
+        let prefix = self.build_prefix(params, segment_start);
+        for second_offset in (0..segment_seconds).step_by(SIMD_BATCH_SIZE as usize) {
+            let batch_size = std::cmp::min(SIMD_BATCH_SIZE, segment_seconds - second_offset);
+            let batch_start = segment_start + second_offset as i64;
+
+            if batch_size == SIMD_BATCH_SIZE {
+                // 8つの秒を並列処理
+                self.process_simd_batch(&prefix, batch_start, params, visitor);
+            } else {
+                // 残りの秒を個別処理
+                self.process_remaining_seconds(&prefix, batch_start, batch_size, params, visitor);
+            }
+        }
+    }
+
+    /// 探索範囲を日付境界で分割し、(区間開始の経過秒, 区間の秒数)を列挙
+    /// 2000年より前の秒は日時コードを持たないため除外する
+    pub(crate) fn day_segments(base_seconds_since_2000: i64, range_seconds: u32) -> impl Iterator<Item = (i64, u32)> {
+        let end = base_seconds_since_2000 + range_seconds as i64;
+        let mut current = base_seconds_since_2000.max(0);
+        std::iter::from_fn(move || {
+            if current >= end {
+                return None;
+            }
+            let segment_end = std::cmp::min((current / 86400 + 1) * 86400, end);
+            let segment = (current, (segment_end - current) as u32);
+            current = segment_end;
+            Some(segment)
+        })
+    }
+
+    /// Timer0/VCountと日付で決まるSHA-1中間状態を構築
+    #[inline]
+    fn build_prefix(&self, params: &SearchParams, seconds_since_2000: i64) -> Sha1Prefix {
+        let date_code = DateCodeGenerator::get_date_code_with_weekday((seconds_since_2000 / 86400) as u32, self.weekday)
+            .expect("search ranges are validated to lie within the date code table");
+        // data[9]（時刻コード）は中間状態に含まれないため0を設定
+        let message = self.build_message(params, date_code, 0);
+        Sha1Prefix::new(&message)
+    }
+
+    /// SIMD バッチ処理（同一日付内の連続する8つの秒を並列計算）
+    /// x86_64ではAVX2、それ以外では4レーンSIMDを2回使用する
+    #[inline]
+    fn process_simd_batch<F>(
+        &self,
+        prefix: &Sha1Prefix,
+        batch_start: i64,
+        params: &SearchParams,
+        visitor: &mut F,
+    ) where
+        F: FnMut(&SearchParams, i64, &HashValues),
+    {
+        // 8つの時刻コード（可変ワード）を準備
+        let time_index = (batch_start % 86400) as u32;
+        let mut time_codes = [0u32; SIMD_BATCH_SIZE as usize];
+        for (i, time_code) in time_codes.iter_mut().enumerate() {
+            *time_code = self.time_code(time_index + i as u32);
+        }
+
+        // SIMD SHA-1計算を実行
+        let hash_results = crate::sha1_simd::calculate_pokemon_sha1_simd_x8_with_prefix(prefix, &time_codes);
+
+        // 各組の結果を処理
+        for i in 0..SIMD_BATCH_SIZE as usize {
+            let hash_values = HashValues {
+                h0: hash_results[i * 5],
+                h1: hash_results[i * 5 + 1],
+                h2: hash_results[i * 5 + 2],
+                h3: hash_results[i * 5 + 3],
+                h4: hash_results[i * 5 + 4],
+            };
+            visitor(params, batch_start + i as i64, &hash_values);
+        }
+    }
+
+    /// 端数秒の個別処理（非SIMD、同一日付内に限る）
+    #[inline]
+    fn process_remaining_seconds<F>(
+        &self,
+        prefix: &Sha1Prefix,
+        batch_start: i64,
+        batch_size: u32,
+        params: &SearchParams,
+        visitor: &mut F,
+    ) where
+        F: FnMut(&SearchParams, i64, &HashValues),
+    {
+        let time_index = (batch_start % 86400) as u32;
+        for i in 0..batch_size {
+            let time_code = self.time_code(time_index + i);
+            let (h0, h1, h2, h3, h4) = calculate_pokemon_sha1_with_prefix(prefix, time_code);
+
+            let hash_values = HashValues { h0, h1, h2, h3, h4 };
+            visitor(params, batch_start + i as i64, &hash_values);
+        }
+    }
+
+    /// 日時コード生成（結果の検証・再構築用）
+    #[inline]
+    fn calculate_datetime_codes(&self, seconds_since_2000: i64) -> Option<(u32, u32)> {
+        if seconds_since_2000 < 0 {
+            return None;
+        }
+
+        let time_index = (seconds_since_2000 % 86400) as u32;
+        let date_index = (seconds_since_2000 / 86400) as u32;
+
+        let time_code = self.profile.time_code(time_index).ok()?;
+        let date_code = DateCodeGenerator::get_date_code_with_weekday(date_index, self.weekday).ok()?;
+
+        Some((time_code, date_code))
+    }
+
+    /// 日内秒の時刻コード（探索区間は日付内に限られる）
+    #[inline(always)]
+    fn time_code(&self, seconds_of_day: u32) -> u32 {
+        self.profile.time_code(seconds_of_day)
+            .expect("seconds of day are validated before searching")
+    }
+
+    /// 結果表示用の日時を生成（マッチした場合のみ）
+    pub(crate) fn generate_display_datetime(seconds_since_2000: i64) -> Option<(u32, u32, u32, u32, u32, u32)> {
+        let result_datetime = chrono::DateTime::from_timestamp(seconds_since_2000 + EPOCH_2000_UNIX, 0)?
+            .naive_utc();
+        
+        Some((
+            result_datetime.year() as u32,
+            result_datetime.month(),
+            result_datetime.day(),
+            result_datetime.hour(),
+            result_datetime.minute(),
+            result_datetime.second()
+        ))
+    }
 
     /// メッセージ構築の共通処理
     #[inline(always)]
-    fn build_message(&self, timer0: u32, vcount: u32, date_code: u32, time_code: u32) -> [u32; 16] {
+    fn build_message(&self, params: &SearchParams, date_code: u32, time_code: u32) -> [u32; 16] {
         let mut message = self.base_message;
-        message[5] = crate::sha1::swap_bytes_32((vcount << 16) | timer0);
+        message[5] = crate::sha1::swap_bytes_32((params.vcount << 16) | params.timer0);
         message[8] = date_code;
         message[9] = time_code;
+        message[12] = crate::sha1::swap_bytes_32(params.key_input);
         message
     }
 
@@ -453,23 +1725,82 @@ impl IntegratedSeedSearcher {
 
     /// 結果チェックと追加（マッチ時のみ日時とハッシュを生成）
     #[inline(always)]
-    fn check_and_add_result(
+    pub(crate) fn check_and_add_result(
         &self,
-        seed: u32,
         hash_values: &HashValues,
         seconds_since_2000: i64,
         params: &SearchParams,
-        target_seeds: &BTreeSet<u32>,
-        results: &js_sys::Array,
+        targets: &TargetMatcher,
+        results: &mut Vec<SearchResult>,
     ) {
-        if target_seeds.contains(&seed) {
-            // マッチした場合のみ日時とハッシュを生成
-            if let Some(datetime) = self.generate_display_datetime(seconds_since_2000) {
-                let (year, month, date, hour, minute, second) = datetime;
-                let hash = self.hash_to_hex_string(hash_values.h0, hash_values.h1, hash_values.h2, hash_values.h3, hash_values.h4);
-                let result = SearchResult::new(seed, hash, year, month, date, hour, minute, second, params.timer0, params.vcount);
-                results.push(&JsValue::from(result));
+        if let Some((seed, lcg_seed)) = Self::match_targets(hash_values, targets) {
+            if let Some(result) = self.build_result(seed, lcg_seed, hash_values, seconds_since_2000, params) {
+                results.push(result);
             }
         }
     }
+
+    /// 結果チェックと固定長レコードの追記（マッチ時もオブジェクトや文字列を生成しない）
+    #[inline(always)]
+    pub(crate) fn check_and_add_record(
+        &self,
+        hash_values: &HashValues,
+        seconds_since_2000: i64,
+        params: &SearchParams,
+        profile_index: u32,
+        targets: &TargetMatcher,
+        records: &mut Vec<u32>,
+    ) {
+        if let Some((seed, lcg_seed)) = Self::match_targets(hash_values, targets) {
+            let record = ResultRecord {
+                seed,
+                lcg_seed,
+                // 日付区間は2000年以降に限られ、2099年末までの経過秒はu32に収まる
+                seconds_since_2000: seconds_since_2000 as u32,
+                timer0: params.timer0,
+                vcount: params.vcount,
+                key_input: params.key_input,
+                profile_index,
+                hash: [hash_values.h0, hash_values.h1, hash_values.h2, hash_values.h3, hash_values.h4],
+            };
+            record.encode_into(records);
+        }
+    }
+
+    /// ハッシュ値からシードを計算し、照合条件に一致すれば(32bitシード, 64bit初期シード)を返す
+    #[inline(always)]
+    fn match_targets(hash_values: &HashValues, targets: &TargetMatcher) -> Option<(u32, u64)> {
+        let lcg_seed = calculate_pokemon_lcg_seed_from_hash(hash_values.h0, hash_values.h1);
+        let seed = (PersonalityRNG::next_seed(lcg_seed) >> 32) as u32;
+        targets.matches(seed, lcg_seed).then_some((seed, lcg_seed))
+    }
+
+    /// マッチした候補の探索結果を構築（再現用の入力とメッセージを含む）
+    #[cold]
+    fn build_result(&self, seed: u32, lcg_seed: u64, hash_values: &HashValues, seconds_since_2000: i64, params: &SearchParams) -> Option<SearchResult> {
+        let (year, month, date, hour, minute, second) = Self::generate_display_datetime(seconds_since_2000)?;
+        let (time_code, date_code) = self.calculate_datetime_codes(seconds_since_2000)?;
+
+        Some(SearchResult {
+            seed,
+            hash: self.hash_to_hex_string(hash_values.h0, hash_values.h1, hash_values.h2, hash_values.h3, hash_values.h4),
+            year,
+            month,
+            date,
+            hour,
+            minute,
+            second,
+            timer0: params.timer0,
+            vcount: params.vcount,
+            key_input: params.key_input,
+            profile_index: 0,
+            mac: self.mac,
+            nazo: self.nazo,
+            hardware: Some(self.profile),
+            message: self.build_message(params, date_code, time_code),
+            lcg_seed,
+            robustness: None,
+            tid_sid: None,
+        })
+    }
 }
@@ -0,0 +1,480 @@
+/// 探索ジョブ仕様
+/// 本体設定・日時範囲・Timer0/VCount範囲・キー入力方針・ターゲットをバージョン付きJSONで記述し、
+/// ブラウザ・ネイティブ・CIのいずれでも同じジョブを解析・検証・実行できるようにする。
+/// `version`と曜日のずらし日数（負の値を取る）を除く数値は、10進数のほか`"0x2FFF"`形式の16進文字列でも書ける
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "profile": { "mac": "12:34:56:78:9A:BC", "hardware": "DS", "rom": { "version": "B2", "region": "JPN" } },
+///   "datetime": { "start": "2012-06-15T00:00:00", "seconds": 86400 },
+///   "keys": { "enumerate": "0x0003" },
+///   "targets": { "seeds": ["0x12345678"] }
+/// }
+/// ```
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::datetime_codes::WeekdayRule;
+use crate::error::InitSeedError;
+use crate::hardware::HardwareProfile;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchRange, SearchResult};
+use crate::key_input::KEY_INPUT_NONE;
+use crate::rom_parameters::{RomParameters, RomRegion, RomVersion};
+use crate::target_matcher::TargetMatcher;
+
+/// 対応するジョブ仕様のバージョン
+pub const JOB_SPEC_VERSION: u32 = 1;
+
+/// 数値または16進文字列
+/// JSONの値のまま保持し、フィールド名付きのエラーを返せるよう使用時に変換する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct SpecNumber(serde_json::Value);
+
+impl SpecNumber {
+    fn value(&self, field: &str) -> Result<u64, InitSeedError> {
+        match &self.0 {
+            serde_json::Value::Number(number) => number
+                .as_u64()
+                .ok_or_else(|| invalid(format!("{field}: {number} is not a non-negative integer"))),
+            serde_json::Value::String(text) => text
+                .strip_prefix("0x")
+                .or_else(|| text.strip_prefix("0X"))
+                // from_str_radixは先頭の'+'を受け付けるため、16進数字だけで構成されることを先に確認する
+                .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|digits| u64::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| invalid(format!("{field}: '{text}' is not a number or 0x-prefixed hex string"))),
+            other => Err(invalid(format!("{field}: {other} is not a number or 0x-prefixed hex string"))),
+        }
+    }
+
+    fn value_u32(&self, field: &str) -> Result<u32, InitSeedError> {
+        u32::try_from(self.value(field)?).map_err(|_| invalid(format!("{field}: value must fit in 32 bits")))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobSpec {
+    version: u32,
+    profile: ProfileSpec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    datetime: Option<DateTimeSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    daily_windows: Option<DailyWindowsSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timer0: Option<[SpecNumber; 2]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vcount: Option<[SpecNumber; 2]>,
+    #[serde(default)]
+    keys: KeyPolicySpec,
+    targets: TargetsSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileSpec {
+    mac: String,
+    hardware: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    frame: Option<SpecNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gx_stat: Option<SpecNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pm_flag: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weekday: Option<WeekdaySpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rom: Option<RomSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nazo: Option<[SpecNumber; 5]>,
+}
+
+/// RTCの曜日: 全日を固定の曜日（0=日曜 … 6=土曜）にする、または暦上の曜日をずらす
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum WeekdaySpec {
+    Fixed(SpecNumber),
+    Offset(i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RomSpec {
+    version: String,
+    region: String,
+}
+
+/// 開始日時（`YYYY-MM-DDTHH:MM:SS`）からの連続した秒数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DateTimeSpec {
+    start: String,
+    seconds: SpecNumber,
+}
+
+/// 開始日から終了日（`YYYY-MM-DD`、両端を含む）までの各日の時間帯`[開始秒, 終了秒)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DailyWindowsSpec {
+    start_date: String,
+    end_date: String,
+    windows: Vec<[SpecNumber; 2]>,
+}
+
+/// キー入力方針: 固定のkey_input値、または許可ボタンの組み合わせを列挙
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum KeyPolicySpec {
+    Fixed(SpecNumber),
+    Enumerate(SpecNumber),
+}
+
+impl Default for KeyPolicySpec {
+    fn default() -> Self {
+        KeyPolicySpec::Fixed(SpecNumber(KEY_INPUT_NONE.into()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MaskSpec {
+    value: SpecNumber,
+    mask: SpecNumber,
+}
+
+/// 照合条件（`TargetMatcher`に対応、範囲は閉区間）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TargetsSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    seeds: Vec<SpecNumber>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    lcg_seeds: Vec<SpecNumber>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    seed_ranges: Vec<[SpecNumber; 2]>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    lcg_seed_ranges: Vec<[SpecNumber; 2]>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    seed_masks: Vec<MaskSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    lcg_seed_masks: Vec<MaskSpec>,
+}
+
+/// 検証済みの日時範囲
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JobSpan {
+    Continuous { start: (u32, u32, u32, u32, u32, u32), seconds: u32 },
+    Daily { start: (u32, u32, u32), end: (u32, u32, u32), windows: Vec<(u32, u32)> },
+}
+
+/// Timer0/VCountの矩形範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct JobBounds {
+    timer0_min: u32,
+    timer0_max: u32,
+    vcount_min: u32,
+    vcount_max: u32,
+}
+
+/// 解析・検証済みの探索ジョブ
+#[wasm_bindgen]
+pub struct SearchJob {
+    spec: JobSpec,
+    searcher: IntegratedSeedSearcher,
+    span: JobSpan,
+    bounds: Vec<JobBounds>,
+    targets: TargetMatcher,
+}
+
+#[wasm_bindgen]
+impl SearchJob {
+    /// JSON形式のジョブ仕様を解析・検証
+    pub fn from_json(text: &str) -> Result<SearchJob, JsValue> {
+        Ok(Self::parse(text)?)
+    }
+
+    /// ジョブ仕様をJSON形式へ書き出す
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.spec).expect("job spec is always serializable")
+    }
+
+    /// ジョブを実行
+    pub fn run(&self) -> Result<js_sys::Array, JsValue> {
+        Ok(IntegratedSeedSearcher::to_js_array(self.try_run()?))
+    }
+
+    /// 探索器の設定フィンガープリント
+    #[wasm_bindgen(getter)]
+    pub fn config_fingerprint(&self) -> u64 {
+        self.searcher.config_fingerprint()
+    }
+}
+
+impl SearchJob {
+    /// JSON形式のジョブ仕様を解析・検証（ネイティブ版）
+    /// 未知のフィールド・未対応バージョン・実行できない範囲はここでエラーになる
+    pub fn parse(text: &str) -> Result<SearchJob, InitSeedError> {
+        let value: serde_json::Value = serde_json::from_str(text).map_err(|error| invalid(error.to_string()))?;
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| invalid("version: missing or not a number".to_string()))?;
+        if version != JOB_SPEC_VERSION as u64 {
+            return Err(InitSeedError::UnsupportedJobVersion(version.min(u32::MAX as u64) as u32));
+        }
+
+        let spec: JobSpec = serde_json::from_value(value).map_err(|error| invalid(error.to_string()))?;
+        Self::compile(spec)
+    }
+
+    /// ジョブを実行（ネイティブ版）
+    /// 結果はTimer0/VCount範囲ごとに各探索と同じ順序で並び、範囲の順に連結される
+    pub fn try_run(&self) -> Result<Vec<SearchResult>, InitSeedError> {
+        let mut results = Vec::new();
+        for bounds in &self.bounds {
+            let JobBounds { timer0_min, timer0_max, vcount_min, vcount_max } = *bounds;
+            results.extend(match &self.span {
+                JobSpan::Continuous { start: (year, month, date, hour, minute, second), seconds } => {
+                    self.searcher.search_seeds_with_targets(
+                        *year, *month, *date, *hour, *minute, *second, *seconds,
+                        timer0_min, timer0_max, vcount_min, vcount_max, &self.targets,
+                    )?
+                }
+                JobSpan::Daily { start: (year_start, month_start, date_start), end: (year_end, month_end, date_end), windows } => {
+                    self.searcher.search_seeds_daily_windows_with_targets(
+                        *year_start, *month_start, *date_start, *year_end, *month_end, *date_end, windows,
+                        timer0_min, timer0_max, vcount_min, vcount_max, &self.targets,
+                    )?
+                }
+            });
+        }
+        Ok(results)
+    }
+
+    /// ジョブの探索器
+    pub fn searcher(&self) -> &IntegratedSeedSearcher {
+        &self.searcher
+    }
+
+    fn compile(spec: JobSpec) -> Result<SearchJob, InitSeedError> {
+        let profile_spec = &spec.profile;
+        let mac = parse_mac(&profile_spec.mac)?;
+
+        let mut profile = HardwareProfile::parse(&profile_spec.hardware)?;
+        if let Some(frame) = &profile_spec.frame {
+            profile = profile.with_frame(frame.value_u32("profile.frame")?);
+        }
+        if let Some(gx_stat) = &profile_spec.gx_stat {
+            profile = profile.with_gx_stat(gx_stat.value_u32("profile.gx_stat")?);
+        }
+        if let Some(pm_flag) = profile_spec.pm_flag {
+            profile = profile.with_pm_flag(pm_flag);
+        }
+
+        let rom = profile_spec
+            .rom
+            .as_ref()
+            .map(|rom| RomParameters::for_hardware(RomVersion::parse(&rom.version)?, RomRegion::parse(&rom.region)?, &profile))
+            .transpose()?;
+        let nazo = match (&rom, &profile_spec.nazo) {
+            (Some(rom), None) => rom.nazo_words().to_vec(),
+            (None, Some(nazo)) => nazo
+                .iter()
+                .map(|word| word.value_u32("profile.nazo"))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(invalid("profile: exactly one of 'rom' and 'nazo' is required".to_string())),
+        };
+
+        let weekday = match &profile_spec.weekday {
+            None => WeekdayRule::Calendar,
+            Some(WeekdaySpec::Fixed(weekday)) => WeekdayRule::fixed(weekday.value_u32("profile.weekday.fixed")?)?,
+            Some(WeekdaySpec::Offset(days)) => WeekdayRule::offset(*days),
+        };
+
+        let searcher = match &spec.keys {
+            KeyPolicySpec::Fixed(key_input) => {
+                IntegratedSeedSearcher::try_from_profile(&mac, &nazo, &profile, key_input.value_u32("keys.fixed")?)?
+            }
+            KeyPolicySpec::Enumerate(allowed_keys) => IntegratedSeedSearcher::try_from_profile_with_key_enumeration(
+                &mac, &nazo, &profile, allowed_keys.value_u32("keys.enumerate")?,
+            )?,
+        }
+        .with_weekday_rule(weekday)?;
+
+        let bounds = match (&spec.timer0, &spec.vcount, &rom) {
+            (Some([timer0_min, timer0_max]), Some([vcount_min, vcount_max]), _) => vec![JobBounds {
+                timer0_min: timer0_min.value_u32("timer0")?,
+                timer0_max: timer0_max.value_u32("timer0")?,
+                vcount_min: vcount_min.value_u32("vcount")?,
+                vcount_max: vcount_max.value_u32("vcount")?,
+            }],
+            (None, None, Some(rom)) => rom
+                .ranges()
+                .iter()
+                .map(|range| JobBounds {
+                    timer0_min: range.timer0_min,
+                    timer0_max: range.timer0_max,
+                    vcount_min: range.vcount,
+                    vcount_max: range.vcount,
+                })
+                .collect(),
+            _ => return Err(invalid("timer0 and vcount must be given together, or omitted to use the ROM ranges".to_string())),
+        };
+
+        let span = match (&spec.datetime, &spec.daily_windows) {
+            (Some(datetime), None) => {
+                let start = parse_datetime(&datetime.start)?;
+                let seconds = datetime.seconds.value_u32("datetime.seconds")?;
+                for bounds in &bounds {
+                    let (year, month, date, hour, minute, second) = start;
+                    SearchRange::from_datetime(
+                        year, month, date, hour, minute, second, seconds,
+                        bounds.timer0_min, bounds.timer0_max, bounds.vcount_min, bounds.vcount_max,
+                    )?;
+                }
+                JobSpan::Continuous { start, seconds }
+            }
+            (None, Some(daily)) => {
+                let start = parse_date(&daily.start_date)?;
+                let end = parse_date(&daily.end_date)?;
+                let windows = daily
+                    .windows
+                    .iter()
+                    .map(|[start, end]| Ok((start.value_u32("daily_windows.windows")?, end.value_u32("daily_windows.windows")?)))
+                    .collect::<Result<Vec<(u32, u32)>, InitSeedError>>()?;
+                IntegratedSeedSearcher::normalize_daily_windows(&windows)?;
+                IntegratedSeedSearcher::daily_span(start.0, start.1, start.2, end.0, end.1, end.2)?;
+                for bounds in &bounds {
+                    SearchRange::check_bounds(bounds.timer0_min, bounds.timer0_max, bounds.vcount_min, bounds.vcount_max)?;
+                }
+                JobSpan::Daily { start, end, windows }
+            }
+            _ => return Err(invalid("exactly one of 'datetime' and 'daily_windows' is required".to_string())),
+        };
+
+        let targets = compile_targets(&spec.targets)?;
+        if targets.is_empty() {
+            return Err(invalid("targets: at least one condition is required".to_string()));
+        }
+
+        Ok(SearchJob { spec, searcher, span, bounds, targets })
+    }
+}
+
+fn invalid(message: String) -> InitSeedError {
+    InitSeedError::InvalidJobSpec(message)
+}
+
+fn compile_targets(spec: &TargetsSpec) -> Result<TargetMatcher, InitSeedError> {
+    // シード一覧と区間は1回の追加でまとめて整列する
+    let seeds = spec.seeds.iter().map(|seed| seed.value_u32("targets.seeds")).collect::<Result<Vec<_>, _>>()?;
+    let lcg_seeds = spec.lcg_seeds.iter().map(|seed| seed.value("targets.lcg_seeds")).collect::<Result<Vec<_>, _>>()?;
+    let seed_ranges = spec
+        .seed_ranges
+        .iter()
+        .map(|[start, end]| Ok((start.value_u32("targets.seed_ranges")?, end.value_u32("targets.seed_ranges")?)))
+        .collect::<Result<Vec<_>, InitSeedError>>()?;
+    let lcg_seed_ranges = spec
+        .lcg_seed_ranges
+        .iter()
+        .map(|[start, end]| Ok((start.value("targets.lcg_seed_ranges")?, end.value("targets.lcg_seed_ranges")?)))
+        .collect::<Result<Vec<_>, InitSeedError>>()?;
+    let mut targets = TargetMatcher::from_seeds(&seeds);
+    targets.add_seeds_u64(&lcg_seeds);
+    targets.add_ranges(&seed_ranges);
+    targets.add_ranges_u64(&lcg_seed_ranges);
+    for MaskSpec { value, mask } in &spec.seed_masks {
+        targets.add_mask(value.value_u32("targets.seed_masks")?, mask.value_u32("targets.seed_masks")?);
+    }
+    for MaskSpec { value, mask } in &spec.lcg_seed_masks {
+        targets.add_mask_u64(value.value("targets.lcg_seed_masks")?, mask.value("targets.lcg_seed_masks")?);
+    }
+    Ok(targets)
+}
+
+/// `12:34:56:78:9A:BC`・`12-34-56-78-9A-BC`・`123456789ABC`形式のMACアドレス
+fn parse_mac(text: &str) -> Result<[u8; 6], InitSeedError> {
+    let octets: Vec<&[u8]> = if text.contains([':', '-']) {
+        text.split([':', '-']).map(str::as_bytes).collect()
+    } else {
+        text.as_bytes().chunks(2).collect()
+    };
+    if octets.len() != 6 || octets.iter().any(|octet| octet.len() != 2 || !octet.iter().all(u8::is_ascii_hexdigit)) {
+        return Err(invalid(format!("profile.mac: '{text}' is not a MAC address")));
+    }
+
+    let mut mac = [0u8; 6];
+    for (byte, octet) in mac.iter_mut().zip(octets) {
+        let hex = |digit: u8| (digit as char).to_digit(16).expect("hex digit checked above") as u8;
+        *byte = (hex(octet[0]) << 4) | hex(octet[1]);
+    }
+    Ok(mac)
+}
+
+/// `YYYY-MM-DD`形式の日付（存在する日付かどうかは探索範囲の検証で判定する）
+fn parse_date(text: &str) -> Result<(u32, u32, u32), InitSeedError> {
+    let fields: Vec<&str> = text.split('-').collect();
+    match fields.as_slice() {
+        [year, month, date] => Ok((parse_field(year, text)?, parse_field(month, text)?, parse_field(date, text)?)),
+        _ => Err(invalid(format!("'{text}' is not a YYYY-MM-DD date"))),
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS`形式の日時（`T`の代わりに空白も可）
+fn parse_datetime(text: &str) -> Result<(u32, u32, u32, u32, u32, u32), InitSeedError> {
+    let (date, time) = text
+        .split_once(['T', ' '])
+        .ok_or_else(|| invalid(format!("'{text}' is not a YYYY-MM-DDTHH:MM:SS datetime")))?;
+    let (year, month, day) = parse_date(date)?;
+    let fields: Vec<&str> = time.split(':').collect();
+    match fields.as_slice() {
+        [hour, minute, second] => Ok((year, month, day, parse_field(hour, text)?, parse_field(minute, text)?, parse_field(second, text)?)),
+        _ => Err(invalid(format!("'{text}' is not a YYYY-MM-DDTHH:MM:SS datetime"))),
+    }
+}
+
+fn parse_field(field: &str, text: &str) -> Result<u32, InitSeedError> {
+    if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid(format!("'{text}' contains a non-numeric date/time field")));
+    }
+    field.parse().map_err(|_| invalid(format!("'{text}' contains a non-numeric date/time field")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_parsers() {
+        assert_eq!(parse_mac("12:34:56:78:9a:BC"), Ok([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+        assert_eq!(parse_mac("12-34-56-78-9A-BC"), parse_mac("123456789ABC"));
+        for malformed in ["12:34:56:78:9A", "12:34:56:78:9A:BG", "1:234:56:78:9A:BC", "12:34:56:78:9A:BC:", "+1:34:56:78:9A:BC"] {
+            assert!(parse_mac(malformed).is_err(), "{malformed}");
+        }
+
+        assert_eq!(parse_datetime("2012-06-15T23:59:54"), Ok((2012, 6, 15, 23, 59, 54)));
+        assert_eq!(parse_datetime("2012-06-15 00:00:00"), Ok((2012, 6, 15, 0, 0, 0)));
+        assert!(parse_datetime("2012-06-15").is_err());
+        assert!(parse_date("2012/06/15").is_err());
+        assert!(parse_date("2012-+6-15").is_err());
+
+        let number = |json: &str| serde_json::from_str::<SpecNumber>(json).unwrap();
+        assert_eq!(number(r#""0x2FFF""#).value("k"), Ok(0x2FFF));
+        assert_eq!(number("12287").value("k"), Ok(0x2FFF));
+        assert!(number(r#""2FFF""#).value("k").is_err());
+        for malformed in [r#""0x""#, r#""0x+7fff""#, r#""0x-1""#, r#""0x 1""#] {
+            assert!(number(malformed).value("k").is_err(), "{malformed}");
+        }
+        assert!(number(&(1u64 << 32).to_string()).value_u32("k").is_err());
+
+        // 負の数・小数・数値以外は、どのフィールドの値かを示すエラーになる
+        for (json, message) in [
+            ("-1", "k: -1 is not a non-negative integer"),
+            ("1.5", "k: 1.5 is not a non-negative integer"),
+            ("true", "k: true is not a number or 0x-prefixed hex string"),
+        ] {
+            assert_eq!(number(json).value("k"), Err(invalid(message.to_string())), "{json}");
+        }
+    }
+}
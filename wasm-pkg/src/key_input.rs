@@ -0,0 +1,147 @@
+/// キー入力列挙
+/// 起動時に押しっぱなしにできるボタンの組み合わせを列挙し、SHA-1メッセージ用のkey_input値を生成する
+use wasm_bindgen::prelude::*;
+
+/// ボタン未入力時のkey_input値（押下されたボタンのビットが0になる）
+pub const KEY_INPUT_NONE: u32 = 0x2FFF;
+
+/// 各ボタンのビット
+pub const KEY_A: u32 = 0x0001;
+pub const KEY_B: u32 = 0x0002;
+pub const KEY_SELECT: u32 = 0x0004;
+pub const KEY_START: u32 = 0x0008;
+pub const KEY_RIGHT: u32 = 0x0010;
+pub const KEY_LEFT: u32 = 0x0020;
+pub const KEY_UP: u32 = 0x0040;
+pub const KEY_DOWN: u32 = 0x0080;
+pub const KEY_R: u32 = 0x0100;
+pub const KEY_L: u32 = 0x0200;
+pub const KEY_X: u32 = 0x0400;
+pub const KEY_Y: u32 = 0x0800;
+
+/// 全ボタンのビットマスク
+pub const KEY_BUTTONS_ALL: u32 = 0x0FFF;
+
+/// 十字キーの物理的に同時押しできない組み合わせ（上下・左右）
+const IMPOSSIBLE_COMBINATIONS: [u32; 2] = [KEY_UP | KEY_DOWN, KEY_LEFT | KEY_RIGHT];
+
+/// L+R+Start+Selectはソフトリセットとなり起動できないため除外する
+const SOFT_RESET_COMBINATION: u32 = KEY_L | KEY_R | KEY_START | KEY_SELECT;
+
+/// キー入力列挙器
+#[wasm_bindgen]
+pub struct KeyInputEnumerator;
+
+#[wasm_bindgen]
+impl KeyInputEnumerator {
+    /// 押下ボタンマスクをkey_input値に変換
+    ///
+    /// # Arguments
+    /// * `pressed_mask` - 押下ボタンのビットマスク（KEY_A等の論理和）
+    ///
+    /// # Returns
+    /// SHA-1メッセージ用のkey_input値
+    pub fn to_key_input(pressed_mask: u32) -> u32 {
+        KEY_INPUT_NONE ^ (pressed_mask & KEY_BUTTONS_ALL)
+    }
+
+    /// key_input値を押下ボタンマスクに変換
+    ///
+    /// # Arguments
+    /// * `key_input` - SHA-1メッセージ用のkey_input値
+    ///
+    /// # Returns
+    /// 押下ボタンのビットマスク
+    pub fn to_pressed_mask(key_input: u32) -> u32 {
+        (KEY_INPUT_NONE ^ key_input) & KEY_BUTTONS_ALL
+    }
+
+    /// 押下ボタンの組み合わせが起動時に入力可能か判定
+    ///
+    /// # Arguments
+    /// * `pressed_mask` - 押下ボタンのビットマスク
+    ///
+    /// # Returns
+    /// 上下・左右の同時押しやソフトリセットを含まない場合true
+    pub fn is_valid_combination(pressed_mask: u32) -> bool {
+        if pressed_mask & !KEY_BUTTONS_ALL != 0 {
+            return false;
+        }
+        for combo in IMPOSSIBLE_COMBINATIONS {
+            if pressed_mask & combo == combo {
+                return false;
+            }
+        }
+        pressed_mask & SOFT_RESET_COMBINATION != SOFT_RESET_COMBINATION
+    }
+
+    /// 許可されたボタンの全組み合わせについてkey_input値を列挙
+    /// 未入力（0x2FFF）を含み、押下マスクの昇順で返す
+    ///
+    /// # Arguments
+    /// * `allowed_mask` - 押してもよいボタンのビットマスク
+    ///
+    /// # Returns
+    /// 入力可能な組み合わせのkey_input値
+    pub fn enumerate(allowed_mask: u32) -> Vec<u32> {
+        let allowed_mask = allowed_mask & KEY_BUTTONS_ALL;
+        let mut key_inputs = Vec::with_capacity(1 << allowed_mask.count_ones());
+
+        // allowed_maskの部分集合を昇順に列挙
+        let mut pressed_mask = 0u32;
+        loop {
+            if Self::is_valid_combination(pressed_mask) {
+                key_inputs.push(Self::to_key_input(pressed_mask));
+            }
+            if pressed_mask == allowed_mask {
+                break;
+            }
+            pressed_mask = pressed_mask.wrapping_sub(allowed_mask) & allowed_mask;
+        }
+
+        key_inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_input_conversion() {
+        assert_eq!(KeyInputEnumerator::to_key_input(0), KEY_INPUT_NONE);
+        assert_eq!(KeyInputEnumerator::to_key_input(KEY_A | KEY_START), 0x2FF6);
+        assert_eq!(KeyInputEnumerator::to_pressed_mask(0x2FF6), KEY_A | KEY_START);
+        assert_eq!(KeyInputEnumerator::to_pressed_mask(KEY_INPUT_NONE), 0);
+    }
+
+    #[test]
+    fn test_invalid_combinations() {
+        assert!(KeyInputEnumerator::is_valid_combination(KEY_UP | KEY_LEFT | KEY_A));
+        assert!(!KeyInputEnumerator::is_valid_combination(KEY_UP | KEY_DOWN));
+        assert!(!KeyInputEnumerator::is_valid_combination(KEY_LEFT | KEY_RIGHT | KEY_B));
+        assert!(!KeyInputEnumerator::is_valid_combination(SOFT_RESET_COMBINATION));
+        assert!(KeyInputEnumerator::is_valid_combination(KEY_L | KEY_R | KEY_START));
+        assert!(!KeyInputEnumerator::is_valid_combination(0x1000));
+    }
+
+    #[test]
+    fn test_enumerate_combinations() {
+        // A, B: 未入力を含む4通り
+        assert_eq!(
+            KeyInputEnumerator::enumerate(KEY_A | KEY_B),
+            vec![0x2FFF, 0x2FFE, 0x2FFD, 0x2FFC]
+        );
+
+        // 十字キー4方向: 3 × 3 = 9通り（上下・左右の同時押しを除外）
+        let dpad = KeyInputEnumerator::enumerate(KEY_UP | KEY_DOWN | KEY_LEFT | KEY_RIGHT);
+        assert_eq!(dpad.len(), 9);
+        assert!(dpad.iter().all(|&key| KeyInputEnumerator::is_valid_combination(KeyInputEnumerator::to_pressed_mask(key))));
+
+        // 全ボタン: 2^8 × 9 から L+R+Start+Selectを含む 2^4 × 9 を除く
+        assert_eq!(KeyInputEnumerator::enumerate(KEY_BUTTONS_ALL).len(), 256 * 9 - 16 * 9);
+
+        // 空集合は未入力のみ
+        assert_eq!(KeyInputEnumerator::enumerate(0), vec![KEY_INPUT_NONE]);
+    }
+}
@@ -1,7 +1,22 @@
+mod error;
 mod datetime_codes;
+mod hardware;
+mod rom_parameters;
 mod sha1;
 mod sha1_simd;
 mod integrated_search;
+mod key_input;
+mod multi_profile_search;
+mod target_matcher;
+mod search_control;
+mod result_records;
+mod likelihood;
+mod calibration;
+mod robustness;
+mod seed_index;
+mod job_spec;
+mod work_plan;
+mod tid_sid_target;
 mod personality_rng;
 mod encounter_calculator;
 mod offset_calculator;
@@ -12,9 +27,36 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
+/// ベンチマーク用の内部SHA-1カーネル（`bench`フィーチャー有効時のみ公開）
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench_support {
+    pub use crate::sha1::{calculate_pokemon_sha1, calculate_pokemon_sha1_with_prefix, Sha1Prefix};
+    pub use crate::sha1_simd::{calculate_pokemon_sha1_simd_with_prefix, calculate_pokemon_sha1_simd_x8_with_prefix};
+    pub use crate::sha1_simd::full_message::{calculate_pokemon_sha1_simd, calculate_pokemon_sha1_simd_x8};
+}
+
 // Re-export main functionality - 統合検索のみ（内部でsha1/sha1_simdは使用）
-pub use datetime_codes::{TimeCodeGenerator, DateCodeGenerator};
+pub use error::InitSeedError;
+pub use datetime_codes::{TimeCodeGenerator, DateCodeGenerator, WeekdayRule};
+pub use hardware::{Hardware, HardwareProfile, NazoVariant};
+pub use rom_parameters::{RomParameters, RomRegion, RomVersion, VCountTimer0Range};
 pub use integrated_search::{SearchResult, IntegratedSeedSearcher};
+pub use multi_profile_search::{SearchProfile, MultiProfileSearcher};
+pub use target_matcher::TargetMatcher;
+pub use search_control::{CancellationToken, SearchCursor, SearchOutcome, SearchPosition, SearchProgress};
+pub use result_records::{ResultRecord, ResultRecordLayout, RECORD_WORDS};
+pub use likelihood::Timer0VCountLikelihood;
+pub use calibration::ConsoleCalibration;
+pub use robustness::{RobustnessWindow, MAX_ROBUSTNESS_RADIUS};
+pub use seed_index::{SeedIndex, SeedIndexHit};
+pub use job_spec::{SearchJob, JOB_SPEC_VERSION};
+pub use work_plan::{WorkPlan, WorkUnit};
+pub use tid_sid_target::TidSidTarget;
+pub use key_input::{
+    KeyInputEnumerator, KEY_INPUT_NONE, KEY_BUTTONS_ALL,
+    KEY_A, KEY_B, KEY_SELECT, KEY_START, KEY_RIGHT, KEY_LEFT, KEY_UP, KEY_DOWN, KEY_R, KEY_L, KEY_X, KEY_Y,
+};
 pub use personality_rng::PersonalityRNG;
 pub use encounter_calculator::{EncounterCalculator, GameVersion, EncounterType};
 pub use offset_calculator::{OffsetCalculator, GameMode, TidSidResult, ExtraResult, calculate_game_offset, calculate_tid_sid_from_seed};
@@ -0,0 +1,183 @@
+/// Timer0/VCount尤度モデル
+/// 同じROMでも本体ごとに出やすいTimer0/VCountの組は偏るため、組ごとの出現確率を推定して結果を並べ替える。
+/// 事前分布はROMパラメータ表の範囲上の一様分布で、実機で観測した組を加えるほど本体に合った分布になる
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::integrated_search::SearchResult;
+use crate::result_records::ResultRecord;
+use crate::rom_parameters::{RomParameters, VCountTimer0Range};
+
+/// Timer0/VCount尤度モデル
+/// 各組の重みは「事前範囲内なら`prior_weight`」＋「観測回数」で、確率は重みを総和で割ったもの
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Timer0VCountLikelihood {
+    prior_ranges: Vec<VCountTimer0Range>,
+    prior_weight: f64,
+    observations: HashMap<(u32, u32), u32>,
+    observation_count: u32,
+}
+
+#[wasm_bindgen]
+impl Timer0VCountLikelihood {
+    /// Timer0/VCountの矩形範囲上の一様分布で作成
+    #[wasm_bindgen(constructor)]
+    pub fn new(timer0_min: u32, timer0_max: u32, vcount_min: u32, vcount_max: u32) -> Timer0VCountLikelihood {
+        let ranges = (vcount_min..=vcount_max)
+            .map(|vcount| VCountTimer0Range { vcount, timer0_min, timer0_max })
+            .collect();
+        Self::from_ranges(ranges)
+    }
+
+    /// ROMパラメータ表の範囲上の一様分布で作成
+    pub fn from_rom(rom: &RomParameters) -> Timer0VCountLikelihood {
+        Self::from_ranges(rom.ranges().to_vec())
+    }
+
+    /// 事前分布の1組あたりの重み（既定値1.0）を設定
+    /// 値が小さいほど観測結果が早く分布に反映される
+    pub fn set_prior_weight(&mut self, prior_weight: f64) {
+        self.prior_weight = prior_weight.max(0.0);
+    }
+
+    /// 実機で初期シードを特定できたTimer0/VCountの組を1件追加
+    pub fn observe(&mut self, timer0: u32, vcount: u32) {
+        self.observe_n(timer0, vcount, 1);
+    }
+
+    /// 同じTimer0/VCountの組を`count`件まとめて追加（件数はu32の上限で飽和する）
+    pub fn observe_n(&mut self, timer0: u32, vcount: u32, count: u32) {
+        let observed = self.observations.entry((timer0, vcount)).or_insert(0);
+        *observed = observed.saturating_add(count);
+        self.observation_count = self.observation_count.saturating_add(count);
+    }
+
+    /// 観測件数
+    #[wasm_bindgen(getter)]
+    pub fn observation_count(&self) -> u32 {
+        self.observation_count
+    }
+
+    /// Timer0/VCountの組の推定出現確率（0.0～1.0）
+    pub fn probability(&self, timer0: u32, vcount: u32) -> f64 {
+        let total = self.total_weight();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        self.weight(timer0, vcount) / total
+    }
+
+    /// 固定長レコード列を推定確率の高い順に並べ替える
+    /// 確率が等しいレコードは元の順序を保つ
+    pub fn rank_records(&self, records: &[u32]) -> Result<Vec<u32>, JsValue> {
+        Ok(self.try_rank_records(records)?)
+    }
+}
+
+impl Timer0VCountLikelihood {
+    /// VCountごとのTimer0範囲上の一様分布で作成（ネイティブ版）
+    pub fn from_ranges(prior_ranges: Vec<VCountTimer0Range>) -> Timer0VCountLikelihood {
+        Timer0VCountLikelihood {
+            prior_ranges,
+            prior_weight: 1.0,
+            observations: HashMap::new(),
+            observation_count: 0,
+        }
+    }
+
+    /// 結果を推定確率の高い順に並べ替える（ネイティブ版）
+    /// 確率が等しい結果は元の順序を保つ
+    pub fn rank_results(&self, results: &mut [SearchResult]) {
+        results.sort_by(|a, b| {
+            self.probability(b.timer0(), b.vcount()).total_cmp(&self.probability(a.timer0(), a.vcount()))
+        });
+    }
+
+    /// 固定長レコード列を推定確率の高い順に並べ替える（ネイティブ版）
+    pub fn try_rank_records(&self, records: &[u32]) -> Result<Vec<u32>, InitSeedError> {
+        let mut decoded = ResultRecord::decode_all(records)?;
+        decoded.sort_by(|a, b| {
+            self.probability(b.timer0, b.vcount).total_cmp(&self.probability(a.timer0, a.vcount))
+        });
+
+        let mut ranked = Vec::with_capacity(records.len());
+        for record in &decoded {
+            record.encode_into(&mut ranked);
+        }
+        Ok(ranked)
+    }
+
+    fn in_prior(&self, timer0: u32, vcount: u32) -> bool {
+        self.prior_ranges
+            .iter()
+            .any(|range| range.vcount == vcount && (range.timer0_min..=range.timer0_max).contains(&timer0))
+    }
+
+    fn weight(&self, timer0: u32, vcount: u32) -> f64 {
+        let prior = if self.in_prior(timer0, vcount) { self.prior_weight } else { 0.0 };
+        prior + self.observations.get(&(timer0, vcount)).copied().unwrap_or(0) as f64
+    }
+
+    fn total_weight(&self) -> f64 {
+        let prior_cells: u64 = self.prior_ranges
+            .iter()
+            .map(|range| (range.timer0_max as u64 + 1).saturating_sub(range.timer0_min as u64))
+            .sum();
+        prior_cells as f64 * self.prior_weight + self.observation_count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::NazoVariant;
+    use crate::rom_parameters::{RomRegion, RomVersion};
+
+    #[test]
+    fn test_uniform_prior_refined_by_observations() {
+        let mut model = Timer0VCountLikelihood::new(0xC79, 0xC7A, 0x60, 0x60);
+        assert_eq!(model.probability(0xC79, 0x60), 0.5);
+        assert_eq!(model.probability(0xC7B, 0x60), 0.0);
+
+        model.observe(0xC7A, 0x60);
+        model.observe(0xC7A, 0x60);
+        assert_eq!(model.observation_count(), 2);
+        assert_eq!(model.probability(0xC7A, 0x60), 0.75);
+        assert_eq!(model.probability(0xC79, 0x60), 0.25);
+
+        // 事前範囲外の観測も分布に加わる
+        model.observe(0xC7B, 0x60);
+        assert_eq!(model.probability(0xC7B, 0x60), 0.2);
+
+        model.set_prior_weight(0.0);
+        assert_eq!(model.probability(0xC79, 0x60), 0.0);
+    }
+
+    #[test]
+    fn test_rom_prior_uses_per_vcount_ranges() {
+        let rom = RomParameters::get(RomVersion::Black2, RomRegion::Ger, NazoVariant::Ds).unwrap();
+        let model = Timer0VCountLikelihood::from_rom(&rom);
+        assert_eq!(model.probability(0x10E5, 0x81), 0.125);
+        assert_eq!(model.probability(0x10E9, 0x82), 0.125);
+        assert_eq!(model.probability(0x10E5, 0x82), 0.0);
+    }
+
+    #[test]
+    fn test_rank_records_is_stable() {
+        let mut model = Timer0VCountLikelihood::new(0xC79, 0xC7B, 0x60, 0x60);
+        model.observe(0xC7B, 0x60);
+
+        let record = |seed, timer0| ResultRecord {
+            seed, lcg_seed: 0, seconds_since_2000: seed, timer0, vcount: 0x60, key_input: 0x2FFF, profile_index: 0, hash: [0; 5],
+        };
+        let mut records = Vec::new();
+        for (seed, timer0) in [(1, 0xC79), (2, 0xC7B), (3, 0xC7A), (4, 0xC7B)] {
+            record(seed, timer0).encode_into(&mut records);
+        }
+
+        let ranked = ResultRecord::decode_all(&model.try_rank_records(&records).unwrap()).unwrap();
+        assert_eq!(ranked.iter().map(|r| r.seed).collect::<Vec<_>>(), vec![2, 4, 1, 3]);
+        assert!(model.try_rank_records(&records[1..]).is_err());
+    }
+}
@@ -0,0 +1,388 @@
+/// 複数プロファイル一括探索
+/// 複数の本体（MAC/ハードウェア）とROM（nazo/Timer0/VCount範囲）の組み合わせを1回の日時走査で探索する
+use wasm_bindgen::prelude::*;
+use crate::calibration::ConsoleCalibration;
+use crate::error::InitSeedError;
+use crate::hardware::HardwareProfile;
+use crate::rom_parameters::{RomRegion, RomVersion};
+use crate::integrated_search::{HashValues, IntegratedSeedSearcher, SearchParams, SearchRange, SearchResult};
+use crate::likelihood::Timer0VCountLikelihood;
+use crate::result_records::ResultRecord;
+use crate::target_matcher::TargetMatcher;
+
+/// 探索プロファイル
+/// 本体・ROMごとの固定パラメータと、そのROMのTimer0/VCount範囲を保持する
+#[wasm_bindgen]
+pub struct SearchProfile {
+    searcher: IntegratedSeedSearcher,
+    timer0_min: u32,
+    timer0_max: u32,
+    vcount_min: u32,
+    vcount_max: u32,
+    // 結果の順位付けに使う尤度モデル（未設定時は範囲上の一様分布）
+    likelihood: Option<Timer0VCountLikelihood>,
+}
+
+#[wasm_bindgen]
+impl SearchProfile {
+    /// コンストラクタ: 固定パラメータの事前計算
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)]  // Profile requires all console and ROM parameters
+    pub fn new(
+        mac: &[u8],
+        nazo: &[u32],
+        hardware: &str,
+        key_input: u32,
+        frame: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SearchProfile, JsValue> {
+        Ok(Self::try_new(mac, nazo, hardware, key_input, frame, timer0_min, timer0_max, vcount_min, vcount_max)?)
+    }
+
+    /// ハードウェアプロファイルからプロファイルを作成
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Profile requires all console and ROM parameters
+    pub fn from_hardware_profile(
+        mac: &[u8],
+        nazo: &[u32],
+        hardware: &HardwareProfile,
+        key_input: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SearchProfile, JsValue> {
+        let searcher = IntegratedSeedSearcher::try_from_profile(mac, nazo, hardware, key_input)?;
+        Ok(Self::from_searcher(searcher, timer0_min, timer0_max, vcount_min, vcount_max)?)
+    }
+
+    /// 結果の順位付けに使う尤度モデルを設定
+    pub fn set_likelihood(&mut self, likelihood: &Timer0VCountLikelihood) {
+        self.likelihood = Some(likelihood.clone());
+    }
+
+    /// このプロファイルの全日の曜日を`weekday`（0=日曜 … 6=土曜）に固定
+    pub fn set_weekday_override(&mut self, weekday: u32) -> Result<(), JsValue> {
+        self.searcher.set_weekday_override(weekday)
+    }
+
+    /// このプロファイルの暦上の曜日を`days`日ずらす（負の値は前の曜日へずらす）
+    pub fn set_weekday_offset(&mut self, days: i32) {
+        self.searcher.set_weekday_offset(days);
+    }
+}
+
+impl SearchProfile {
+    /// コンストラクタ（ネイティブ版）
+    #[allow(clippy::too_many_arguments)]  // Profile requires all console and ROM parameters
+    pub fn try_new(
+        mac: &[u8],
+        nazo: &[u32],
+        hardware: &str,
+        key_input: u32,
+        frame: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SearchProfile, InitSeedError> {
+        let searcher = IntegratedSeedSearcher::try_new(mac, nazo, hardware, key_input, frame)?;
+        Self::from_searcher(searcher, timer0_min, timer0_max, vcount_min, vcount_max)
+    }
+
+    /// ROMパラメータデータベースからプロファイルを作成
+    /// VCountごとにTimer0範囲が異なるROMでは、VCount値ごとに1つのプロファイルを返す。
+    /// 分割した各プロファイルにはROM全体の範囲上の一様分布を尤度モデルとして設定し、
+    /// VCountごとのセル数の違いで順位が偏らないようにする
+    pub fn from_rom(
+        mac: &[u8],
+        version: RomVersion,
+        region: RomRegion,
+        hardware: &HardwareProfile,
+        key_input: u32,
+    ) -> Result<Vec<SearchProfile>, InitSeedError> {
+        let template = IntegratedSeedSearcher::try_from_rom(mac, version, region, hardware, key_input)?;
+        let likelihood = Timer0VCountLikelihood::from_ranges(template.default_ranges().to_vec());
+        template
+            .default_ranges()
+            .iter()
+            .map(|range| {
+                let mut profile = Self::from_searcher(template.with_key_input(key_input), range.timer0_min, range.timer0_max, range.vcount, range.vcount)?;
+                profile.set_likelihood(&likelihood);
+                Ok(profile)
+            })
+            .collect()
+    }
+
+    /// 既存の探索器（キー入力列挙モードを含む）からプロファイルを作成
+    pub fn from_searcher(
+        searcher: IntegratedSeedSearcher,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SearchProfile, InitSeedError> {
+        SearchRange::check_bounds(timer0_min, timer0_max, vcount_min, vcount_max)?;
+        Ok(SearchProfile { searcher, timer0_min, timer0_max, vcount_min, vcount_max, likelihood: None })
+    }
+
+    /// このプロファイルでのTimer0/VCountの推定出現確率
+    fn probability(&self, timer0: u32, vcount: u32) -> f64 {
+        match &self.likelihood {
+            Some(likelihood) => likelihood.probability(timer0, vcount),
+            None => {
+                let in_range = (self.timer0_min..=self.timer0_max).contains(&timer0)
+                    && (self.vcount_min..=self.vcount_max).contains(&vcount);
+                let cells = (self.timer0_max - self.timer0_min + 1) as f64 * (self.vcount_max - self.vcount_min + 1) as f64;
+                if in_range { 1.0 / cells } else { 0.0 }
+            }
+        }
+    }
+}
+
+/// 複数プロファイル探索器
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct MultiProfileSearcher {
+    profiles: Vec<SearchProfile>,
+}
+
+#[wasm_bindgen]
+impl MultiProfileSearcher {
+    /// 空の探索器を作成
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> MultiProfileSearcher {
+        MultiProfileSearcher { profiles: Vec::new() }
+    }
+
+    /// プロファイルを追加し、そのプロファイル番号を返す
+    pub fn add_profile(&mut self, profile: SearchProfile) -> u32 {
+        self.profiles.push(profile);
+        (self.profiles.len() - 1) as u32
+    }
+
+    /// ROMパラメータデータベースの既定範囲でプロファイルを追加し、追加したプロファイル数を返す
+    /// 追加されたプロファイルの番号は追加前の`profile_count`から連番になる
+    pub fn add_rom_profiles(
+        &mut self,
+        mac: &[u8],
+        version: RomVersion,
+        region: RomRegion,
+        hardware: &HardwareProfile,
+        key_input: u32,
+    ) -> Result<u32, JsValue> {
+        let profiles = SearchProfile::from_rom(mac, version, region, hardware, key_input)?;
+        let count = profiles.len() as u32;
+        self.profiles.extend(profiles);
+        Ok(count)
+    }
+
+    /// 本体キャリブレーションで絞り込んだ範囲でプロファイルを追加し、追加したプロファイル数を返す
+    /// 追加されたプロファイルの番号は追加前の`profile_count`から連番になる
+    pub fn add_calibrated_profiles(&mut self, searcher: &IntegratedSeedSearcher, calibration: &ConsoleCalibration) -> Result<u32, JsValue> {
+        let profiles = calibration.search_profiles(searcher)?;
+        let count = profiles.len() as u32;
+        self.profiles.extend(profiles);
+        Ok(count)
+    }
+
+    /// 登録済みプロファイルの尤度モデルを設定
+    pub fn set_profile_likelihood(&mut self, profile_index: u32, likelihood: &Timer0VCountLikelihood) -> Result<(), JsValue> {
+        let profile = self.profiles
+            .get_mut(profile_index as usize)
+            .ok_or(InitSeedError::UnknownProfile(profile_index))?;
+        profile.set_likelihood(likelihood);
+        Ok(())
+    }
+
+    /// 登録済みプロファイル数
+    #[wasm_bindgen(getter)]
+    pub fn profile_count(&self) -> u32 {
+        self.profiles.len() as u32
+    }
+
+    /// 全プロファイルを一括探索
+    /// 各結果の`profile_index`にヒットしたプロファイル番号が設定される
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        target_seeds: &[u32],
+    ) -> Result<js_sys::Array, JsValue> {
+        let results = self.search_seeds(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, target_seeds,
+        )?;
+        Ok(IntegratedSeedSearcher::to_js_array(results))
+    }
+
+    /// 全プロファイルを一括探索し、各プロファイルの尤度モデルによる推定確率の高い順に返す
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_ranked(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        target_seeds: &[u32],
+    ) -> Result<js_sys::Array, JsValue> {
+        let mut results = self.search_seeds(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, target_seeds,
+        )?;
+        self.rank_results(&mut results);
+        Ok(IntegratedSeedSearcher::to_js_array(results))
+    }
+
+    /// 全プロファイルを一括探索し、固定長レコードで返す
+    /// 各レコードのプロファイル番号にヒットしたプロファイルが設定される
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds_integrated_records(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<u32>, JsValue> {
+        Ok(self.search_seed_records(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, targets,
+        )?)
+    }
+
+    /// 固定長レコードを各プロファイルの`SearchResult`に展開
+    #[wasm_bindgen]
+    pub fn decode_result_records(&self, records: &[u32]) -> Result<js_sys::Array, JsValue> {
+        self.try_decode_result_records(records)
+            .map(IntegratedSeedSearcher::to_js_array)
+            .map_err(JsValue::from)
+    }
+}
+
+impl MultiProfileSearcher {
+    /// 全プロファイルを一括探索（ネイティブ版）
+    /// 日時範囲は1回だけ走査し、日付ごとに全プロファイルの中間状態を構築する。
+    /// 結果は日付 → プロファイル → Timer0 → VCount → キー入力 → 秒の順に並ぶ
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seeds(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        target_seeds: &[u32],
+    ) -> Result<Vec<SearchResult>, InitSeedError> {
+        let base_seconds_since_2000 = IntegratedSeedSearcher::seconds_since_2000(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+        )?;
+        SearchRange::check_seconds(base_seconds_since_2000, range_seconds)?;
+
+        let targets = TargetMatcher::from_seeds(target_seeds);
+        let mut results = Vec::new();
+        self.for_each_hash(base_seconds_since_2000, range_seconds, &mut |profile_index, searcher, params, seconds_since_2000, hash_values| {
+            let found_before = results.len();
+            searcher.check_and_add_result(hash_values, seconds_since_2000, params, &targets, &mut results);
+            if results.len() > found_before {
+                results[found_before].set_profile_index(profile_index);
+            }
+        });
+
+        Ok(results)
+    }
+
+    /// 全プロファイルを一括探索し、固定長レコードで返す（ネイティブ版）
+    /// レコードの順序は`search_seeds`と同一
+    #[allow(clippy::too_many_arguments)]  // Search function requires comprehensive parameters
+    pub fn search_seed_records(
+        &self,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        targets: &TargetMatcher,
+    ) -> Result<Vec<u32>, InitSeedError> {
+        let base_seconds_since_2000 = IntegratedSeedSearcher::seconds_since_2000(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+        )?;
+        SearchRange::check_seconds(base_seconds_since_2000, range_seconds)?;
+
+        let mut records = Vec::new();
+        self.for_each_hash(base_seconds_since_2000, range_seconds, &mut |profile_index, searcher, params, seconds_since_2000, hash_values| {
+            searcher.check_and_add_record(hash_values, seconds_since_2000, params, profile_index, targets, &mut records);
+        });
+
+        Ok(records)
+    }
+
+    /// 結果をヒットしたプロファイルの尤度モデルによる推定確率の高い順に並べ替える（ネイティブ版）
+    /// 確率が等しい結果は元の順序を保つ
+    pub fn rank_results(&self, results: &mut [SearchResult]) {
+        let probability = |result: &SearchResult| {
+            self.profiles
+                .get(result.profile_index() as usize)
+                .map_or(0.0, |profile| profile.probability(result.timer0(), result.vcount()))
+        };
+        results.sort_by(|a, b| probability(b).total_cmp(&probability(a)));
+    }
+
+    /// 固定長レコードを各プロファイルの`SearchResult`に展開（ネイティブ版）
+    pub fn try_decode_result_records(&self, records: &[u32]) -> Result<Vec<SearchResult>, InitSeedError> {
+        ResultRecord::decode_all(records)?
+            .iter()
+            .map(|record| {
+                let profile = self.profiles
+                    .get(record.profile_index as usize)
+                    .ok_or(InitSeedError::UnknownProfile(record.profile_index))?;
+                profile.searcher.expand_record(record)
+            })
+            .collect()
+    }
+
+    /// 全プロファイルの候補についてSHA-1を計算し、visitorへ渡す
+    /// 外側ループは日付で、日時コードの走査を全プロファイルで共有する
+    fn for_each_hash<F>(&self, base_seconds_since_2000: i64, range_seconds: u32, visitor: &mut F)
+    where
+        F: FnMut(u32, &IntegratedSeedSearcher, &SearchParams, i64, &HashValues),
+    {
+        for (segment_start, segment_seconds) in IntegratedSeedSearcher::day_segments(base_seconds_since_2000, range_seconds) {
+            for (profile_index, profile) in self.profiles.iter().enumerate() {
+                let searcher = &profile.searcher;
+                for timer0 in profile.timer0_min..=profile.timer0_max {
+                    for vcount in profile.vcount_min..=profile.vcount_max {
+                        for &key_input in searcher.key_inputs() {
+                            let params = SearchParams { timer0, vcount, key_input };
+                            searcher.process_segment_simd(&params, segment_start, segment_seconds, &mut |params, seconds_since_2000, hash_values| {
+                                visitor(profile_index as u32, searcher, params, seconds_since_2000, hash_values);
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -195,9 +195,9 @@ impl OffsetCalculator {
             [100, 100, 100, 100, 100], // L6
         ];
 
-        for level in 0..6 {  // L1からL6まで
-            for j in 0..5 {   // 各レベルで最大5つの閾値をチェック
-                if PT_TABLES[level][j] == 100 {
+        for table in PT_TABLES.iter() {  // L1からL6まで
+            for &threshold in table.iter() {   // 各レベルで最大5つの閾値をチェック
+                if threshold == 100 {
                     // 確率が100なら、次のレベルへ
                     break;
                 }
@@ -206,7 +206,7 @@ impl OffsetCalculator {
                 // 仕様書の計算式: r = ((rand_value as u64 * 101) >> 32) as u32
                 let r = ((rand_value as u64 * 101) >> 32) as u32;
                 
-                if r <= PT_TABLES[level][j] {
+                if r <= threshold {
                     // 取得した確率がテーブルの値以下なら次のレベルへ
                     break;
                 }
@@ -26,6 +26,7 @@ impl PersonalityRNG {
     /// # Returns
     /// 上位32bitの乱数値
     #[inline]
+    #[allow(clippy::should_implement_trait)]  // wasm_bindgenでJSへ公開するためIteratorは実装しない
     pub fn next(&mut self) -> u32 {
         // BW仕様線形合同法
         self.seed = self.seed.wrapping_mul(0x5D588B656C078965).wrapping_add(0x269EC3);
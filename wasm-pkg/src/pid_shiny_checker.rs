@@ -18,6 +18,12 @@ pub enum ShinyType {
 #[wasm_bindgen]
 pub struct PIDCalculator;
 
+impl Default for PIDCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl PIDCalculator {
     /// 新しいPIDCalculatorインスタンスを作成
@@ -161,7 +167,7 @@ impl PIDCalculator {
     /// 生成されたPID
     pub fn generate_egg_pid(r1: u32, r2: u32) -> u32 {
         // タマゴは上位16bitと下位16bitを組み合わせる
-        ((r1 & 0xFFFF0000) >> 16) | ((r2 & 0xFFFF0000))
+        ((r1 & 0xFFFF0000) >> 16) | (r2 & 0xFFFF0000)
     }
 }
 
@@ -169,6 +175,12 @@ impl PIDCalculator {
 #[wasm_bindgen]
 pub struct ShinyChecker;
 
+impl Default for ShinyChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl ShinyChecker {
     /// 新しいShinyCheckerインスタンスを作成
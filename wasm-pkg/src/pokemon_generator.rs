@@ -129,6 +129,12 @@ impl BWGenerationConfig {
 #[wasm_bindgen]
 pub struct PokemonGenerator;
 
+impl Default for PokemonGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl PokemonGenerator {
     /// 新しいPokemonGeneratorインスタンスを作成
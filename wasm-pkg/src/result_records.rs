@@ -0,0 +1,157 @@
+/// 固定長結果レコード
+/// 探索ヒットを`SearchResult`オブジェクトやハッシュ文字列を経由せず`Vec<u32>`へ書き出す。
+/// JS側では`Uint32Array`として受け取り、Workerからゼロコピーで転送できる
+///
+/// 1件あたり`RECORD_WORDS`ワードで、各ワードの位置は以下の通り:
+///
+/// | 位置 | 内容 |
+/// |------|------|
+/// | 0 | 32bitシード |
+/// | 1 | 64bit LCG初期シードの下位32bit |
+/// | 2 | 64bit LCG初期シードの上位32bit |
+/// | 3 | 2000年1月1日からの経過秒 |
+/// | 4 | Timer0 |
+/// | 5 | VCount |
+/// | 6 | key_input |
+/// | 7 | プロファイル番号 |
+/// | 8-12 | SHA-1ハッシュ値 h0～h4 |
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+
+/// 1レコードのワード数
+pub const RECORD_WORDS: usize = 13;
+
+const OFFSET_SEED: usize = 0;
+const OFFSET_LCG_SEED_LOW: usize = 1;
+const OFFSET_LCG_SEED_HIGH: usize = 2;
+const OFFSET_SECONDS_SINCE_2000: usize = 3;
+const OFFSET_TIMER0: usize = 4;
+const OFFSET_VCOUNT: usize = 5;
+const OFFSET_KEY_INPUT: usize = 6;
+const OFFSET_PROFILE_INDEX: usize = 7;
+const OFFSET_HASH: usize = 8;
+
+/// レコードレイアウト（JS側でUint32Arrayを直接読むためのワード位置）
+#[wasm_bindgen]
+pub struct ResultRecordLayout;
+
+#[wasm_bindgen]
+impl ResultRecordLayout {
+    /// 1レコードのワード数
+    pub fn words() -> u32 { RECORD_WORDS as u32 }
+    /// 32bitシードの位置
+    pub fn seed() -> u32 { OFFSET_SEED as u32 }
+    /// 64bit LCG初期シード下位32bitの位置
+    pub fn lcg_seed_low() -> u32 { OFFSET_LCG_SEED_LOW as u32 }
+    /// 64bit LCG初期シード上位32bitの位置
+    pub fn lcg_seed_high() -> u32 { OFFSET_LCG_SEED_HIGH as u32 }
+    /// 2000年1月1日からの経過秒の位置
+    pub fn seconds_since_2000() -> u32 { OFFSET_SECONDS_SINCE_2000 as u32 }
+    /// Timer0の位置
+    pub fn timer0() -> u32 { OFFSET_TIMER0 as u32 }
+    /// VCountの位置
+    pub fn vcount() -> u32 { OFFSET_VCOUNT as u32 }
+    /// key_inputの位置
+    pub fn key_input() -> u32 { OFFSET_KEY_INPUT as u32 }
+    /// プロファイル番号の位置
+    pub fn profile_index() -> u32 { OFFSET_PROFILE_INDEX as u32 }
+    /// ハッシュ値h0の位置（h1～h4が続く）
+    pub fn hash() -> u32 { OFFSET_HASH as u32 }
+}
+
+/// デコード済みの結果レコード
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResultRecord {
+    pub seed: u32,
+    pub lcg_seed: u64,
+    /// 2000年1月1日からの経過秒
+    pub seconds_since_2000: u32,
+    pub timer0: u32,
+    pub vcount: u32,
+    pub key_input: u32,
+    pub profile_index: u32,
+    /// SHA-1ハッシュ値 h0～h4
+    pub hash: [u32; 5],
+}
+
+impl ResultRecord {
+    /// バッファの末尾にレコードを追記
+    #[inline]
+    pub fn encode_into(&self, buffer: &mut Vec<u32>) {
+        buffer.extend_from_slice(&[
+            self.seed,
+            self.lcg_seed as u32,
+            (self.lcg_seed >> 32) as u32,
+            self.seconds_since_2000,
+            self.timer0,
+            self.vcount,
+            self.key_input,
+            self.profile_index,
+            self.hash[0],
+            self.hash[1],
+            self.hash[2],
+            self.hash[3],
+            self.hash[4],
+        ]);
+    }
+
+    /// 1レコード分のワード列からデコード
+    pub fn decode(words: &[u32]) -> Result<ResultRecord, InitSeedError> {
+        if words.len() != RECORD_WORDS {
+            return Err(InitSeedError::InvalidResultRecords);
+        }
+
+        let mut hash = [0u32; 5];
+        hash.copy_from_slice(&words[OFFSET_HASH..OFFSET_HASH + 5]);
+
+        Ok(ResultRecord {
+            seed: words[OFFSET_SEED],
+            lcg_seed: ((words[OFFSET_LCG_SEED_HIGH] as u64) << 32) | words[OFFSET_LCG_SEED_LOW] as u64,
+            seconds_since_2000: words[OFFSET_SECONDS_SINCE_2000],
+            timer0: words[OFFSET_TIMER0],
+            vcount: words[OFFSET_VCOUNT],
+            key_input: words[OFFSET_KEY_INPUT],
+            profile_index: words[OFFSET_PROFILE_INDEX],
+            hash,
+        })
+    }
+
+    /// レコード列全体をデコード
+    pub fn decode_all(records: &[u32]) -> Result<Vec<ResultRecord>, InitSeedError> {
+        if !records.len().is_multiple_of(RECORD_WORDS) {
+            return Err(InitSeedError::InvalidResultRecords);
+        }
+        records.chunks_exact(RECORD_WORDS).map(Self::decode).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_round_trip() {
+        let record = ResultRecord {
+            seed: 0x12345678,
+            lcg_seed: 0x9ABCDEF0_11223344,
+            seconds_since_2000: 392_904_000,
+            timer0: 0xC79,
+            vcount: 0x60,
+            key_input: 0x2FFE,
+            profile_index: 3,
+            hash: [1, 2, 3, 4, 5],
+        };
+
+        let mut buffer = Vec::new();
+        record.encode_into(&mut buffer);
+        record.encode_into(&mut buffer);
+        assert_eq!(buffer.len(), 2 * RECORD_WORDS);
+        assert_eq!(buffer[OFFSET_LCG_SEED_LOW], 0x11223344);
+        assert_eq!(buffer[OFFSET_LCG_SEED_HIGH], 0x9ABCDEF0);
+        assert_eq!(ResultRecord::decode_all(&buffer), Ok(vec![record, record]));
+
+        assert!(ResultRecord::decode(&buffer[..RECORD_WORDS - 1]).is_err());
+        assert!(ResultRecord::decode_all(&buffer[..RECORD_WORDS + 1]).is_err());
+        assert_eq!(ResultRecord::decode_all(&[]), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,105 @@
+/// 起動タイミングの頑健性評価
+/// ヒットした起動条件の周辺（前後の秒・隣接するTimer0/VCount）でもターゲットに一致するかを数え、
+/// 多少のタイミングずれでも目的のシードに届く起動時刻を優先できるようにする
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::integrated_search::SearchParams;
+
+/// 各方向の半径の上限
+/// 評価コストはヒット1件あたり(2r+1)^3回のSHA-1になるため、隣接値の確認に足りる範囲に制限する
+pub const MAX_ROBUSTNESS_RADIUS: u32 = 8;
+
+/// 頑健性評価の周辺範囲
+/// ヒットから各方向に半径分ずらした組み合わせ（ヒット自身を除く）を周辺とする
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RobustnessWindow {
+    second_radius: u32,
+    timer0_radius: u32,
+    vcount_radius: u32,
+}
+
+#[wasm_bindgen]
+impl RobustnessWindow {
+    /// 周辺範囲を作成（各半径は`MAX_ROBUSTNESS_RADIUS`以下）
+    #[wasm_bindgen(constructor)]
+    pub fn new(second_radius: u32, timer0_radius: u32, vcount_radius: u32) -> Result<RobustnessWindow, JsValue> {
+        Self::try_new(second_radius, timer0_radius, vcount_radius).map_err(JsValue::from)
+    }
+
+    /// 前後に評価する秒数
+    #[wasm_bindgen(getter)]
+    pub fn second_radius(&self) -> u32 {
+        self.second_radius
+    }
+
+    /// 前後に評価するTimer0の幅
+    #[wasm_bindgen(getter)]
+    pub fn timer0_radius(&self) -> u32 {
+        self.timer0_radius
+    }
+
+    /// 前後に評価するVCountの幅
+    #[wasm_bindgen(getter)]
+    pub fn vcount_radius(&self) -> u32 {
+        self.vcount_radius
+    }
+}
+
+impl RobustnessWindow {
+    /// 周辺範囲を作成（ネイティブ版、各半径は`MAX_ROBUSTNESS_RADIUS`以下）
+    pub fn try_new(second_radius: u32, timer0_radius: u32, vcount_radius: u32) -> Result<RobustnessWindow, InitSeedError> {
+        if let Some(&radius) = [second_radius, timer0_radius, vcount_radius].iter().find(|&&radius| radius > MAX_ROBUSTNESS_RADIUS) {
+            return Err(InitSeedError::RobustnessRadiusTooLarge(radius));
+        }
+        Ok(RobustnessWindow { second_radius, timer0_radius, vcount_radius })
+    }
+
+    /// ヒット周辺の(経過秒, パラメータ)を列挙（負の値、および16bitを超えるTimer0/VCountになる組は除く）
+    pub(crate) fn neighbours(&self, seconds_since_2000: i64, params: SearchParams) -> impl Iterator<Item = (i64, SearchParams)> + '_ {
+        let offsets = |radius: u32| -(radius as i64)..=radius as i64;
+
+        offsets(self.second_radius).flat_map(move |second_offset| {
+            offsets(self.timer0_radius).flat_map(move |timer0_offset| {
+                offsets(self.vcount_radius).filter_map(move |vcount_offset| {
+                    if (second_offset, timer0_offset, vcount_offset) == (0, 0, 0) {
+                        return None;
+                    }
+                    let seconds = seconds_since_2000 + second_offset;
+                    let timer0 = u16::try_from(params.timer0 as i64 + timer0_offset).ok()? as u32;
+                    let vcount = u16::try_from(params.vcount as i64 + vcount_offset).ok()? as u32;
+                    (seconds >= 0).then_some((seconds, SearchParams { timer0, vcount, key_input: params.key_input }))
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours_exclude_center_and_negative_values() {
+        let params = SearchParams { timer0: 0xC79, vcount: 0x60, key_input: 0x2FFF };
+        let window = RobustnessWindow::try_new(1, 1, 1).unwrap();
+        assert_eq!(window.neighbours(100, params).count(), 26);
+        assert!(window.neighbours(100, params).all(|(seconds, p)| (seconds, p.timer0, p.vcount) != (100, 0xC79, 0x60)));
+
+        // 2000-01-01 00:00:00 より前の秒は含めない
+        assert_eq!(window.neighbours(0, params).count(), 17);
+        assert_eq!(RobustnessWindow::try_new(2, 0, 0).unwrap().neighbours(100, params).count(), 4);
+    }
+
+    #[test]
+    fn test_neighbours_stay_within_16_bits() {
+        // Timer0/VCountはメッセージ内で16bitずつ詰めるため、0xFFFFを超える組は含めない
+        let window = RobustnessWindow::try_new(0, 1, 1).unwrap();
+        let top = SearchParams { timer0: 0xFFFF, vcount: 0xFFFF, key_input: 0x2FFF };
+        let neighbours: Vec<_> = window.neighbours(100, top).map(|(_, p)| (p.timer0, p.vcount)).collect();
+        assert_eq!(neighbours, vec![(0xFFFE, 0xFFFE), (0xFFFE, 0xFFFF), (0xFFFF, 0xFFFE)]);
+
+        let bottom = SearchParams { timer0: 0, vcount: 0, key_input: 0x2FFF };
+        assert_eq!(window.neighbours(100, bottom).count(), 3);
+    }
+}
@@ -0,0 +1,314 @@
+/// ROMパラメータデータベース
+/// BW/BW2全28種（バージョン4種 × 言語7種）のnazo値とVCount/Timer0範囲を、本体のnazo系統（`NazoVariant`）ごとに保持する。
+/// DS系の値は`src/data/rom-parameters.ts`と同一（出典: https://blog.bzl-web.com/entry/2020/09/18/235128）。
+/// BW2はDSi・3DSでもDS系と同じ値で動作する。BWはDSiモードのメモリ配置でnazo値が変わるが、
+/// 検証済みの値がまだないため、DSi・3DSでの取得は`UnsupportedRomHardware`エラーになる
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::hardware::{HardwareProfile, NazoVariant};
+
+/// ROMバージョン
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RomVersion {
+    Black = 0,
+    White = 1,
+    Black2 = 2,
+    White2 = 3,
+}
+
+impl RomVersion {
+    /// 全バージョン
+    pub const ALL: [RomVersion; 4] = [RomVersion::Black, RomVersion::White, RomVersion::Black2, RomVersion::White2];
+
+    /// バージョン名（"B" / "W" / "B2" / "W2"）から変換
+    pub fn parse(name: &str) -> Result<RomVersion, InitSeedError> {
+        match name {
+            "B" => Ok(RomVersion::Black),
+            "W" => Ok(RomVersion::White),
+            "B2" => Ok(RomVersion::Black2),
+            "W2" => Ok(RomVersion::White2),
+            _ => Err(InitSeedError::UnknownRomVersion(name.to_string())),
+        }
+    }
+
+    /// バージョン名（TypeScript側のキーと同じ表記）
+    pub fn name(self) -> &'static str {
+        match self {
+            RomVersion::Black => "B",
+            RomVersion::White => "W",
+            RomVersion::Black2 => "B2",
+            RomVersion::White2 => "W2",
+        }
+    }
+}
+
+/// ROMの言語（リージョン）
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RomRegion {
+    Jpn = 0,
+    Kor = 1,
+    Usa = 2,
+    Ger = 3,
+    Fra = 4,
+    Spa = 5,
+    Ita = 6,
+}
+
+impl RomRegion {
+    /// 全言語
+    pub const ALL: [RomRegion; 7] = [
+        RomRegion::Jpn, RomRegion::Kor, RomRegion::Usa, RomRegion::Ger, RomRegion::Fra, RomRegion::Spa, RomRegion::Ita,
+    ];
+
+    /// 言語名（"JPN" / "KOR" / "USA" / "GER" / "FRA" / "SPA" / "ITA"）から変換
+    pub fn parse(name: &str) -> Result<RomRegion, InitSeedError> {
+        match name {
+            "JPN" => Ok(RomRegion::Jpn),
+            "KOR" => Ok(RomRegion::Kor),
+            "USA" => Ok(RomRegion::Usa),
+            "GER" => Ok(RomRegion::Ger),
+            "FRA" => Ok(RomRegion::Fra),
+            "SPA" => Ok(RomRegion::Spa),
+            "ITA" => Ok(RomRegion::Ita),
+            _ => Err(InitSeedError::UnknownRomRegion(name.to_string())),
+        }
+    }
+
+    /// 言語名（TypeScript側のキーと同じ表記）
+    pub fn name(self) -> &'static str {
+        match self {
+            RomRegion::Jpn => "JPN",
+            RomRegion::Kor => "KOR",
+            RomRegion::Usa => "USA",
+            RomRegion::Ger => "GER",
+            RomRegion::Fra => "FRA",
+            RomRegion::Spa => "SPA",
+            RomRegion::Ita => "ITA",
+        }
+    }
+}
+
+/// VCount値と、そのVCountで取り得るTimer0範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VCountTimer0Range {
+    pub vcount: u32,
+    pub timer0_min: u32,
+    pub timer0_max: u32,
+}
+
+const fn range(vcount: u32, timer0_min: u32, timer0_max: u32) -> VCountTimer0Range {
+    VCountTimer0Range { vcount, timer0_min, timer0_max }
+}
+
+/// ROMパラメータ
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RomParameters {
+    version: RomVersion,
+    region: RomRegion,
+    variant: NazoVariant,
+    nazo: [u32; 5],
+    ranges: &'static [VCountTimer0Range],
+}
+
+const fn rom(
+    version: RomVersion,
+    region: RomRegion,
+    nazo: [u32; 5],
+    ranges: &'static [VCountTimer0Range],
+) -> RomParameters {
+    RomParameters { version, region, variant: NazoVariant::Ds, nazo, ranges }
+}
+
+use RomRegion::{Fra, Ger, Ita, Jpn, Kor, Spa, Usa};
+use RomVersion::{Black, Black2, White, White2};
+
+/// DS・DS Lite向けのバージョン × 言語のパラメータ表（`RomVersion`・`RomRegion`の値順）
+const ROM_PARAMETERS: [[RomParameters; 7]; 4] = [
+    [
+        rom(Black, Jpn, [0x02215F10, 0x0221600C, 0x0221600C, 0x02216058, 0x02216058], &[range(0x60, 0xC79, 0xC7A)]),
+        rom(Black, Kor, [0x022167B0, 0x022168AC, 0x022168AC, 0x022168F8, 0x022168F8], &[range(0x60, 0xC84, 0xC85)]),
+        rom(Black, Usa, [0x022160B0, 0x022161AC, 0x022161AC, 0x022161F8, 0x022161F8], &[range(0x60, 0xC7B, 0xC7C)]),
+        rom(Black, Ger, [0x02215FF0, 0x022160EC, 0x022160EC, 0x02216138, 0x02216138], &[range(0x5F, 0xC77, 0xC78)]),
+        rom(Black, Fra, [0x02216030, 0x0221612C, 0x0221612C, 0x02216178, 0x02216178], &[range(0x5F, 0xC73, 0xC74)]),
+        rom(Black, Spa, [0x02216070, 0x0221616C, 0x0221616C, 0x022161B8, 0x022161B8], &[range(0x60, 0xC86, 0xC87)]),
+        rom(Black, Ita, [0x02215FB0, 0x022160AC, 0x022160AC, 0x022160F8, 0x022160F8], &[range(0x5F, 0xC6A, 0xC6B)]),
+    ],
+    [
+        rom(White, Jpn, [0x02215F30, 0x0221602C, 0x0221602C, 0x02216078, 0x02216078], &[range(0x5F, 0xC67, 0xC69)]),
+        rom(White, Kor, [0x022167B0, 0x022168AC, 0x022168AC, 0x022168F8, 0x022168F8], &[range(0x60, 0xC7B, 0xC7C)]),
+        rom(White, Usa, [0x022160D0, 0x022161CC, 0x022161CC, 0x02216218, 0x02216218], &[range(0x60, 0xC7E, 0xC80)]),
+        rom(White, Ger, [0x02216010, 0x0221610C, 0x0221610C, 0x02216158, 0x02216158], &[range(0x60, 0xC7A, 0xC7B)]),
+        rom(White, Fra, [0x02216050, 0x0221614C, 0x0221614C, 0x02216198, 0x02216198], &[range(0x5F, 0xC6E, 0xC6F)]),
+        rom(White, Spa, [0x02216070, 0x0221616C, 0x0221616C, 0x022161B8, 0x022161B8], &[range(0x5F, 0xC70, 0xC71)]),
+        rom(White, Ita, [0x02215FD0, 0x022160CC, 0x022160CC, 0x02216118, 0x02216118], &[range(0x60, 0xC7B, 0xC7C)]),
+    ],
+    [
+        rom(Black2, Jpn, [0x0209A8DC, 0x02039AC9, 0x021FF9B0, 0x021FFA04, 0x021FFA04], &[range(0x82, 0x1102, 0x1108)]),
+        rom(Black2, Kor, [0x0209B60C, 0x0203A4D5, 0x02200750, 0x022007A4, 0x022007A4], &[range(0x82, 0x10EF, 0x10F4)]),
+        rom(Black2, Usa, [0x0209AEE8, 0x02039DE9, 0x02200010, 0x02200064, 0x02200064], &[range(0x82, 0x1102, 0x1108)]),
+        rom(Black2, Ger, [0x0209AE28, 0x02039D69, 0x021FFF50, 0x021FFFA4, 0x021FFFA4], &[range(0x81, 0x10E5, 0x10E8), range(0x82, 0x10E9, 0x10EC)]),
+        rom(Black2, Fra, [0x0209AF08, 0x02039DF9, 0x02200030, 0x02200084, 0x02200084], &[range(0x82, 0x10F4, 0x10F8)]),
+        rom(Black2, Spa, [0x0209AEA8, 0x02039DB9, 0x021FFFD0, 0x02200024, 0x02200024], &[range(0x82, 0x1101, 0x1106)]),
+        rom(Black2, Ita, [0x0209ADE8, 0x02039D69, 0x021FFF10, 0x021FFF64, 0x021FFF64], &[range(0x82, 0x1107, 0x1109), range(0x83, 0x1109, 0x110D)]),
+    ],
+    [
+        rom(White2, Jpn, [0x0209A8FC, 0x02039AF5, 0x021FF9D0, 0x021FFA24, 0x021FFA24], &[range(0x82, 0x10F5, 0x10FB)]),
+        rom(White2, Kor, [0x0209B62C, 0x0203A501, 0x02200770, 0x022007C4, 0x022007C4], &[range(0x81, 0x10E4, 0x10E9)]),
+        rom(White2, Usa, [0x0209AF28, 0x02039E15, 0x02200050, 0x022000A4, 0x022000A4], &[range(0x82, 0x10F2, 0x10F6)]),
+        rom(White2, Ger, [0x0209AE48, 0x02039D95, 0x021FFF70, 0x021FFFC4, 0x021FFFC4], &[range(0x82, 0x10E5, 0x10ED)]),
+        rom(White2, Fra, [0x0209AF28, 0x02039E25, 0x02200050, 0x022000A4, 0x022000A4], &[range(0x82, 0x10EC, 0x10F0)]),
+        rom(White2, Spa, [0x0209AEC8, 0x02039DE5, 0x021FFFF0, 0x02200044, 0x02200044], &[range(0x82, 0x10EF, 0x10F4)]),
+        rom(White2, Ita, [0x0209AE28, 0x02039D95, 0x021FFF50, 0x021FFFA4, 0x021FFFA4], &[range(0x82, 0x10FF, 0x1104)]),
+    ],
+];
+
+#[wasm_bindgen]
+impl RomParameters {
+    /// バージョン・言語・nazo系統のパラメータを取得
+    #[wasm_bindgen(constructor)]
+    pub fn new(version: RomVersion, region: RomRegion, variant: NazoVariant) -> Result<RomParameters, JsValue> {
+        Ok(Self::get(version, region, variant)?)
+    }
+
+    /// バージョン名・言語名・本体種別名（"B"・"JPN"・"DSI"等）からパラメータを取得
+    pub fn from_names(version: &str, region: &str, hardware: &str) -> Result<RomParameters, JsValue> {
+        Ok(Self::parse(version, region, hardware)?)
+    }
+
+    /// ROMバージョン
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> RomVersion {
+        self.version
+    }
+
+    /// ROMの言語
+    #[wasm_bindgen(getter)]
+    pub fn region(&self) -> RomRegion {
+        self.region
+    }
+
+    /// nazo値の系統
+    #[wasm_bindgen(getter)]
+    pub fn nazo_variant(&self) -> NazoVariant {
+        self.variant
+    }
+
+    /// nazo値（5ワード）
+    #[wasm_bindgen(getter)]
+    pub fn nazo(&self) -> Vec<u32> {
+        self.nazo.to_vec()
+    }
+
+    /// VCount/Timer0範囲の平坦配列（`[vcount, timer0_min, timer0_max]`の繰り返し）
+    #[wasm_bindgen(getter)]
+    pub fn vcount_timer0_ranges(&self) -> Vec<u32> {
+        self.ranges
+            .iter()
+            .flat_map(|range| [range.vcount, range.timer0_min, range.timer0_max])
+            .collect()
+    }
+
+    /// 指定VCountで取り得るTimer0範囲（`[timer0_min, timer0_max]`、該当なしはundefined）
+    pub fn timer0_range(&self, vcount: u32) -> Option<Vec<u32>> {
+        self.ranges
+            .iter()
+            .find(|range| range.vcount == vcount)
+            .map(|range| vec![range.timer0_min, range.timer0_max])
+    }
+}
+
+impl RomParameters {
+    /// バージョン・言語・nazo系統のパラメータを取得（ネイティブ版）
+    pub fn get(version: RomVersion, region: RomRegion, variant: NazoVariant) -> Result<RomParameters, InitSeedError> {
+        let ds = ROM_PARAMETERS[version as usize][region as usize];
+        match (variant, version) {
+            (NazoVariant::Ds, _) => Ok(ds),
+            (NazoVariant::Dsi, RomVersion::Black2 | RomVersion::White2) => Ok(RomParameters { variant, ..ds }),
+            (NazoVariant::Dsi, RomVersion::Black | RomVersion::White) => Err(InitSeedError::UnsupportedRomHardware {
+                version: version.name().to_string(),
+                region: region.name().to_string(),
+            }),
+        }
+    }
+
+    /// ハードウェアプロファイルに対応するパラメータを取得（ネイティブ版）
+    pub fn for_hardware(version: RomVersion, region: RomRegion, hardware: &HardwareProfile) -> Result<RomParameters, InitSeedError> {
+        Self::get(version, region, hardware.nazo_variant())
+    }
+
+    /// バージョン名・言語名・本体種別名からパラメータを取得（ネイティブ版）
+    pub fn parse(version: &str, region: &str, hardware: &str) -> Result<RomParameters, InitSeedError> {
+        Self::for_hardware(RomVersion::parse(version)?, RomRegion::parse(region)?, &HardwareProfile::parse(hardware)?)
+    }
+
+    /// nazo値
+    pub fn nazo_words(&self) -> &[u32; 5] {
+        &self.nazo
+    }
+
+    /// VCount/Timer0範囲
+    pub fn ranges(&self) -> &'static [VCountTimer0Range] {
+        self.ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_indexed_by_version_and_region() {
+        for version in RomVersion::ALL {
+            for region in RomRegion::ALL {
+                let parameters = RomParameters::get(version, region, NazoVariant::Ds).unwrap();
+                assert_eq!((parameters.version(), parameters.region()), (version, region));
+                assert_eq!(parameters.nazo_variant(), NazoVariant::Ds);
+                assert_eq!(RomParameters::parse(version.name(), region.name(), "DS"), Ok(parameters));
+                assert_eq!(RomParameters::parse(version.name(), region.name(), "DS_LITE"), Ok(parameters));
+                assert!(!parameters.ranges().is_empty());
+                assert!(parameters.ranges().iter().all(|range| range.timer0_min <= range.timer0_max));
+            }
+        }
+
+        let b_jpn = RomParameters::get(RomVersion::Black, RomRegion::Jpn, NazoVariant::Ds).unwrap();
+        assert_eq!(b_jpn.nazo_words(), &[0x02215F10, 0x0221600C, 0x0221600C, 0x02216058, 0x02216058]);
+        assert_eq!(b_jpn.vcount_timer0_ranges(), vec![0x60, 0xC79, 0xC7A]);
+
+        let b2_ger = RomParameters::get(RomVersion::Black2, RomRegion::Ger, NazoVariant::Ds).unwrap();
+        assert_eq!(b2_ger.timer0_range(0x81), Some(vec![0x10E5, 0x10E8]));
+        assert_eq!(b2_ger.timer0_range(0x82), Some(vec![0x10E9, 0x10EC]));
+        assert_eq!(b2_ger.timer0_range(0x83), None);
+
+        assert_eq!(RomParameters::parse("X", "JPN", "DS"), Err(InitSeedError::UnknownRomVersion("X".to_string())));
+        assert_eq!(RomParameters::parse("B", "CHN", "DS"), Err(InitSeedError::UnknownRomRegion("CHN".to_string())));
+        assert_eq!(RomParameters::parse("B", "JPN", "GBA"), Err(InitSeedError::UnknownHardware("GBA".to_string())));
+    }
+
+    #[test]
+    fn test_table_is_keyed_by_hardware() {
+        // BW2はDSi・3DSでもDS系と同じnazo値・範囲
+        for version in [RomVersion::Black2, RomVersion::White2] {
+            for region in RomRegion::ALL {
+                let ds = RomParameters::get(version, region, NazoVariant::Ds).unwrap();
+                for hardware in ["DSI", "3DS"] {
+                    let dsi = RomParameters::parse(version.name(), region.name(), hardware).unwrap();
+                    assert_eq!(dsi.nazo_variant(), NazoVariant::Dsi);
+                    assert_eq!((dsi.nazo_words(), dsi.ranges()), (ds.nazo_words(), ds.ranges()));
+                }
+            }
+        }
+
+        // BWのDSi・3DS向けnazo値はDS系と異なるため、DS系の値で代用しない
+        for version in [RomVersion::Black, RomVersion::White] {
+            for region in RomRegion::ALL {
+                assert_eq!(
+                    RomParameters::get(version, region, NazoVariant::Dsi),
+                    Err(InitSeedError::UnsupportedRomHardware { version: version.name().to_string(), region: region.name().to_string() })
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+/// 探索制御
+/// 長時間の探索に対する進捗通知・協調的キャンセル・途中結果の受け渡しを提供する
+use wasm_bindgen::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use crate::error::InitSeedError;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchRange, SearchResult};
+
+/// キャンセルトークン
+/// 探索ループは探索単位（Timer0・VCount・キー入力・日付の組）の境界でこのトークンを確認する。
+/// ネイティブ環境ではcloneしたトークンを別スレッドから`cancel`できる
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[wasm_bindgen]
+impl CancellationToken {
+    /// 未キャンセル状態のトークンを作成
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// キャンセルを要求
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// キャンセル要求を取り消して再利用可能にする
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+
+    /// キャンセルが要求されているか
+    #[wasm_bindgen(getter)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// 探索の進捗
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchProgress {
+    /// 処理済みの探索単位数
+    pub completed_units: u64,
+    /// 探索単位の総数
+    pub total_units: u64,
+    /// これまでに見つかった結果数
+    pub results_found: u32,
+    /// この時点から再開するためのチェックポイント
+    pub cursor: SearchCursor,
+}
+
+/// 探索再開用カーソル（チェックポイント）
+/// 探索範囲と次に処理する探索単位の番号、探索器設定の指紋を保持する。
+/// 探索単位はTimer0 → VCount → キー入力 → 日付の順に番号付けされる
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchCursor {
+    fingerprint: u64,
+    base_seconds_since_2000: i64,
+    range_seconds: u32,
+    timer0_min: u32,
+    timer0_max: u32,
+    vcount_min: u32,
+    vcount_max: u32,
+    next_unit: u64,
+}
+
+#[wasm_bindgen]
+impl SearchCursor {
+    /// 文字列形式へシリアライズ（localStorage等への保存用）
+    pub fn serialize(&self) -> String {
+        format!(
+            "{}:{:016x}:{}:{}:{}:{}:{}:{}:{}",
+            Self::FORMAT_VERSION,
+            self.fingerprint,
+            self.base_seconds_since_2000,
+            self.range_seconds,
+            self.timer0_min,
+            self.timer0_max,
+            self.vcount_min,
+            self.vcount_max,
+            self.next_unit,
+        )
+    }
+
+    /// 文字列形式からデシリアライズ
+    pub fn deserialize(text: &str) -> Result<SearchCursor, JsValue> {
+        Ok(Self::parse(text)?)
+    }
+
+    /// 探索器設定の指紋
+    #[wasm_bindgen(getter)]
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// 次に処理する探索単位の番号（処理済みの単位数）
+    #[wasm_bindgen(getter)]
+    pub fn next_unit(&self) -> u64 {
+        self.next_unit
+    }
+}
+
+impl SearchCursor {
+    /// シリアライズ形式のバージョン
+    const FORMAT_VERSION: &'static str = "v1";
+
+    pub(crate) fn new(fingerprint: u64, range: &SearchRange, next_unit: u64) -> SearchCursor {
+        SearchCursor {
+            fingerprint,
+            base_seconds_since_2000: range.base_seconds_since_2000,
+            range_seconds: range.range_seconds,
+            timer0_min: range.timer0_min,
+            timer0_max: range.timer0_max,
+            vcount_min: range.vcount_min,
+            vcount_max: range.vcount_max,
+            next_unit,
+        }
+    }
+
+    /// 文字列形式からデシリアライズ（ネイティブ版）
+    pub fn parse(text: &str) -> Result<SearchCursor, InitSeedError> {
+        let fields: Vec<&str> = text.trim().split(':').collect();
+        if fields.len() != 9 || fields[0] != Self::FORMAT_VERSION {
+            return Err(InitSeedError::InvalidCheckpoint);
+        }
+        let number = |index: usize| fields[index].parse::<u32>().map_err(|_| InitSeedError::InvalidCheckpoint);
+
+        Ok(SearchCursor {
+            fingerprint: u64::from_str_radix(fields[1], 16).map_err(|_| InitSeedError::InvalidCheckpoint)?,
+            base_seconds_since_2000: fields[2].parse().map_err(|_| InitSeedError::InvalidCheckpoint)?,
+            range_seconds: number(3)?,
+            timer0_min: number(4)?,
+            timer0_max: number(5)?,
+            vcount_min: number(6)?,
+            vcount_max: number(7)?,
+            next_unit: fields[8].parse().map_err(|_| InitSeedError::InvalidCheckpoint)?,
+        })
+    }
+
+    /// カーソルが保持する探索範囲
+    pub(crate) fn range(&self) -> SearchRange {
+        SearchRange {
+            base_seconds_since_2000: self.base_seconds_since_2000,
+            range_seconds: self.range_seconds,
+            timer0_min: self.timer0_min,
+            timer0_max: self.timer0_max,
+            vcount_min: self.vcount_min,
+            vcount_max: self.vcount_max,
+        }
+    }
+}
+
+/// FNV-1a（64bit）ハッシュ
+/// 探索器設定の指紋計算に使用する
+pub(crate) fn fnv1a_64(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// 探索を中断した位置（次に処理する探索単位）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchPosition {
+    pub timer0: u32,
+    pub vcount: u32,
+    pub key_input: u32,
+    /// 探索単位の開始時刻（2000年1月1日からの経過秒）
+    pub seconds_since_2000: i64,
+}
+
+/// 中断可能な探索の結果
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct SearchOutcome {
+    results: Vec<SearchResult>,
+    completed_units: u64,
+    total_units: u64,
+    stopped_at: Option<SearchPosition>,
+    cursor: Option<SearchCursor>,
+}
+
+#[wasm_bindgen]
+impl SearchOutcome {
+    /// 見つかった結果（キャンセル時は中断までの途中結果）
+    #[wasm_bindgen(getter)]
+    pub fn results(&self) -> js_sys::Array {
+        IntegratedSeedSearcher::to_js_array(self.results.clone())
+    }
+
+    /// キャンセルにより中断したか
+    #[wasm_bindgen(getter)]
+    pub fn cancelled(&self) -> bool {
+        self.stopped_at.is_some()
+    }
+
+    /// 処理済みの探索単位数
+    #[wasm_bindgen(getter)]
+    pub fn completed_units(&self) -> u64 {
+        self.completed_units
+    }
+
+    /// 探索単位の総数
+    #[wasm_bindgen(getter)]
+    pub fn total_units(&self) -> u64 {
+        self.total_units
+    }
+
+    /// 中断位置のTimer0（完了時はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn stopped_timer0(&self) -> Option<u32> {
+        self.stopped_at.map(|position| position.timer0)
+    }
+
+    /// 中断位置のVCount（完了時はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn stopped_vcount(&self) -> Option<u32> {
+        self.stopped_at.map(|position| position.vcount)
+    }
+
+    /// 中断位置のkey_input（完了時はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn stopped_key_input(&self) -> Option<u32> {
+        self.stopped_at.map(|position| position.key_input)
+    }
+
+    /// 中断位置の開始時刻（2000年1月1日からの経過秒、完了時はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn stopped_seconds_since_2000(&self) -> Option<i64> {
+        self.stopped_at.map(|position| position.seconds_since_2000)
+    }
+
+    /// 中断位置から再開するためのカーソル（完了時はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn cursor(&self) -> Option<SearchCursor> {
+        self.cursor
+    }
+}
+
+impl SearchOutcome {
+    pub(crate) fn new(
+        results: Vec<SearchResult>,
+        completed_units: u64,
+        total_units: u64,
+        stopped: Option<(SearchPosition, SearchCursor)>,
+    ) -> SearchOutcome {
+        SearchOutcome {
+            results,
+            completed_units,
+            total_units,
+            stopped_at: stopped.map(|(position, _)| position),
+            cursor: stopped.map(|(_, cursor)| cursor),
+        }
+    }
+
+    /// 見つかった結果（ネイティブ版）
+    pub fn result_list(&self) -> &[SearchResult] {
+        &self.results
+    }
+
+    /// 結果を取り出す（ネイティブ版）
+    pub fn into_results(self) -> Vec<SearchResult> {
+        self.results
+    }
+
+    /// 中断位置（完了時はNone）
+    pub fn stopped_at(&self) -> Option<SearchPosition> {
+        self.stopped_at
+    }
+}
@@ -0,0 +1,322 @@
+/// シードインデックス
+/// 本体・ROM・キー入力が固定なら「日時 × Timer0 × VCount → シード」の対応は変わらないため、
+/// 日付範囲の全候補のシードを一度だけ計算し、シード順に並べたバイナリ形式で保存する。
+/// 検索はハッシュ計算なしの二分探索で行う
+///
+/// ファイル形式（リトルエンディアン）:
+///
+/// | 内容 | 型 |
+/// |------|----|
+/// | マジック`G5SI` | 4バイト |
+/// | 形式バージョン | u32 |
+/// | 探索器設定の指紋 | u64 |
+/// | 開始時刻（2000年1月1日からの経過秒） | i64 |
+/// | 秒数・Timer0最小・最大・VCount最小・最大 | u32 × 5 |
+/// | key_input数Kとkey_input値 | u32 × (1 + K) |
+/// | エントリ数Nとエントリ | u32 + u64 × N |
+///
+/// エントリは上位32bitがシード、下位32bitが候補番号（Timer0 → VCount → キー入力 → 秒の順の通し番号）で、昇順に並ぶ
+use std::io::{Read, Write};
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchRange};
+
+/// インデックスの検索結果（シードを与える起動条件）
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedIndexHit {
+    seed: u32,
+    seconds_since_2000: i64,
+    timer0: u32,
+    vcount: u32,
+    key_input: u32,
+    datetime: (u32, u32, u32, u32, u32, u32),
+}
+
+#[wasm_bindgen]
+impl SeedIndexHit {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> u32 { self.seed }
+    /// 2000年1月1日からの経過秒
+    #[wasm_bindgen(getter)]
+    pub fn seconds_since_2000(&self) -> i64 { self.seconds_since_2000 }
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> u32 { self.datetime.0 }
+    #[wasm_bindgen(getter)]
+    pub fn month(&self) -> u32 { self.datetime.1 }
+    #[wasm_bindgen(getter)]
+    pub fn date(&self) -> u32 { self.datetime.2 }
+    #[wasm_bindgen(getter)]
+    pub fn hour(&self) -> u32 { self.datetime.3 }
+    #[wasm_bindgen(getter)]
+    pub fn minute(&self) -> u32 { self.datetime.4 }
+    #[wasm_bindgen(getter)]
+    pub fn second(&self) -> u32 { self.datetime.5 }
+    #[wasm_bindgen(getter)]
+    pub fn timer0(&self) -> u32 { self.timer0 }
+    #[wasm_bindgen(getter)]
+    pub fn vcount(&self) -> u32 { self.vcount }
+    #[wasm_bindgen(getter)]
+    pub fn key_input(&self) -> u32 { self.key_input }
+}
+
+/// シードインデックス
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedIndex {
+    fingerprint: u64,
+    range: SearchRange,
+    key_inputs: Vec<u32>,
+    // (シード << 32) | 候補番号 の昇順
+    entries: Vec<u64>,
+}
+
+#[wasm_bindgen]
+impl SeedIndex {
+    /// 探索器と日時・Timer0/VCount範囲からインデックスを構築
+    #[allow(clippy::too_many_arguments)]  // Index covers the same range parameters as a search
+    pub fn build(
+        searcher: &IntegratedSeedSearcher,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SeedIndex, JsValue> {
+        Ok(Self::try_build(
+            searcher, year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?)
+    }
+
+    /// バイナリ形式からインデックスを読み込む
+    pub fn from_bytes(bytes: &[u8]) -> Result<SeedIndex, JsValue> {
+        Ok(Self::parse(bytes)?)
+    }
+
+    /// バイナリ形式へ書き出す（ファイル保存用）
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::HEADER_BYTES + 4 * self.key_inputs.len() + 8 * self.entries.len());
+        self.write_to(&mut bytes).expect("writing to Vec<u8> cannot fail");
+        bytes
+    }
+
+    /// シードを与える起動条件を検索（Timer0 → VCount → キー入力 → 日時の順）
+    pub fn lookup(&self, seed: u32) -> js_sys::Array {
+        self.find(seed).into_iter().map(JsValue::from).collect()
+    }
+
+    /// 探索器の設定がインデックス構築時と同じか
+    pub fn matches_searcher(&self, searcher: &IntegratedSeedSearcher) -> bool {
+        searcher.config_fingerprint() == self.fingerprint
+    }
+
+    /// 探索器設定の指紋
+    #[wasm_bindgen(getter)]
+    pub fn config_fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// エントリ数（候補の総数）
+    #[wasm_bindgen(getter)]
+    pub fn entry_count(&self) -> u32 {
+        self.entries.len() as u32
+    }
+}
+
+impl SeedIndex {
+    const MAGIC: [u8; 4] = *b"G5SI";
+    const FORMAT_VERSION: u32 = 1;
+    /// key_input値とエントリを除くヘッダ長
+    const HEADER_BYTES: usize = 4 + 4 + 8 + 8 + 4 * 5 + 4 + 4;
+
+    /// 探索器と日時・Timer0/VCount範囲からインデックスを構築（ネイティブ版）
+    #[allow(clippy::too_many_arguments)]  // Index covers the same range parameters as a search
+    pub fn try_build(
+        searcher: &IntegratedSeedSearcher,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+    ) -> Result<SeedIndex, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        match Self::candidate_count(&range, searcher.key_inputs().len()) {
+            Some(count) if count < 1u64 << 32 => {}
+            _ => return Err(InitSeedError::SeedIndexTooLarge),
+        }
+
+        // list_seedsはTimer0 → VCount → キー入力 → 秒の順に並ぶため、位置がそのまま候補番号になる
+        let seeds = searcher.list_seeds(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        let mut entries: Vec<u64> = seeds
+            .iter()
+            .enumerate()
+            .map(|(candidate, &seed)| ((seed as u64) << 32) | candidate as u64)
+            .collect();
+        entries.sort_unstable();
+
+        Ok(SeedIndex {
+            fingerprint: searcher.config_fingerprint(),
+            range,
+            key_inputs: searcher.key_inputs().to_vec(),
+            entries,
+        })
+    }
+
+    /// シードを与える起動条件を検索（ネイティブ版）
+    pub fn find(&self, seed: u32) -> Vec<SeedIndexHit> {
+        let start = self.entries.partition_point(|&entry| ((entry >> 32) as u32) < seed);
+        self.entries[start..]
+            .iter()
+            .take_while(|&&entry| (entry >> 32) as u32 == seed)
+            .map(|&entry| self.decode_candidate(seed, entry as u32))
+            .collect()
+    }
+
+    /// バイナリ形式で書き出す（ネイティブ版）
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&Self::MAGIC)?;
+        writer.write_all(&Self::FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&self.fingerprint.to_le_bytes())?;
+        writer.write_all(&self.range.base_seconds_since_2000.to_le_bytes())?;
+        for value in [
+            self.range.range_seconds,
+            self.range.timer0_min,
+            self.range.timer0_max,
+            self.range.vcount_min,
+            self.range.vcount_max,
+            self.key_inputs.len() as u32,
+        ] {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        for key_input in &self.key_inputs {
+            writer.write_all(&key_input.to_le_bytes())?;
+        }
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(&entry.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// バイナリ形式から読み込む（ネイティブ版、ファイル等から）
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<SeedIndex, InitSeedError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(|_| InitSeedError::InvalidSeedIndex)?;
+        Self::parse(&bytes)
+    }
+
+    /// バイナリ形式から読み込む（ネイティブ版）
+    /// 範囲・候補数・並び順が整合しないデータは拒否する
+    pub fn parse(bytes: &[u8]) -> Result<SeedIndex, InitSeedError> {
+        let mut reader = ByteReader { bytes };
+        if reader.take(4)? != Self::MAGIC || reader.u32()? != Self::FORMAT_VERSION {
+            return Err(InitSeedError::InvalidSeedIndex);
+        }
+
+        let fingerprint = reader.u64()?;
+        let range = SearchRange {
+            base_seconds_since_2000: reader.u64()? as i64,
+            range_seconds: reader.u32()?,
+            timer0_min: reader.u32()?,
+            timer0_max: reader.u32()?,
+            vcount_min: reader.u32()?,
+            vcount_max: reader.u32()?,
+        };
+        range.validate().map_err(|_| InitSeedError::InvalidSeedIndex)?;
+
+        let key_count = reader.u32()? as usize;
+        let key_inputs = (0..key_count).map(|_| reader.u32()).collect::<Result<Vec<_>, _>>()?;
+        let entry_count = reader.u32()? as u64;
+        if key_inputs.is_empty() || Self::candidate_count(&range, key_inputs.len()) != Some(entry_count) {
+            return Err(InitSeedError::InvalidSeedIndex);
+        }
+
+        let entries = (0..entry_count).map(|_| reader.u64()).collect::<Result<Vec<_>, _>>()?;
+        let sorted = entries.windows(2).all(|pair| pair[0] < pair[1]);
+        let in_range = entries.iter().all(|&entry| (entry as u32 as u64) < entry_count);
+        if !reader.bytes.is_empty() || !sorted || !in_range {
+            return Err(InitSeedError::InvalidSeedIndex);
+        }
+
+        Ok(SeedIndex { fingerprint, range, key_inputs, entries })
+    }
+
+    /// 候補数（u64に収まらない場合はNone）
+    fn candidate_count(range: &SearchRange, key_count: usize) -> Option<u64> {
+        [
+            (range.timer0_max - range.timer0_min) as u64 + 1,
+            (range.vcount_max - range.vcount_min) as u64 + 1,
+            key_count as u64,
+            range.range_seconds as u64,
+        ]
+        .into_iter()
+        .try_fold(1u64, |total, count| total.checked_mul(count))
+    }
+
+    /// 候補番号を起動条件に戻す
+    fn decode_candidate(&self, seed: u32, candidate: u32) -> SeedIndexHit {
+        let range_seconds = self.range.range_seconds as u64;
+        let key_count = self.key_inputs.len() as u64;
+        let vcount_count = (self.range.vcount_max - self.range.vcount_min + 1) as u64;
+
+        let candidate = candidate as u64;
+        let second_offset = candidate % range_seconds;
+        let key_index = (candidate / range_seconds) % key_count;
+        let vcount_index = (candidate / range_seconds / key_count) % vcount_count;
+        let timer0_index = candidate / range_seconds / key_count / vcount_count;
+
+        let seconds_since_2000 = self.range.base_seconds_since_2000 + second_offset as i64;
+        SeedIndexHit {
+            seed,
+            seconds_since_2000,
+            timer0: self.range.timer0_min + timer0_index as u32,
+            vcount: self.range.vcount_min + vcount_index as u32,
+            key_input: self.key_inputs[key_index as usize],
+            // 範囲は読み込み時に日時コード表内であることを検証済み
+            datetime: IntegratedSeedSearcher::generate_display_datetime(seconds_since_2000).unwrap_or_default(),
+        }
+    }
+}
+
+/// 先頭から順に読み進めるバイト列リーダー
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], InitSeedError> {
+        if self.bytes.len() < length {
+            return Err(InitSeedError::InvalidSeedIndex);
+        }
+        let (head, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, InitSeedError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().expect("length checked")))
+    }
+
+    fn u64(&mut self) -> Result<u64, InitSeedError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().expect("length checked")))
+    }
+}
@@ -1,5 +1,5 @@
-/// ポケモンBW/BW2特化SHA-1実装
-/// 高速なシード計算のためにカスタム最適化されたSHA-1関数
+//! ポケモンBW/BW2特化SHA-1実装
+//! 高速なシード計算のためにカスタム最適化されたSHA-1関数
 
 /// ポケモンBW/BW2のSHA-1実装
 /// 16個の32bit値を受け取り、h0～h4の5つのハッシュ値を返す
@@ -67,15 +67,131 @@ pub fn calculate_pokemon_sha1(message: &[u32; 16]) -> (u32, u32, u32, u32, u32)
     (final_h0, final_h1, final_h2, final_h3, final_h4)
 }
 
+/// 探索中に毎秒変化するメッセージワードの位置（data[9]: 時刻コード）
+pub const VARIABLE_WORD_INDEX: usize = 9;
+
+/// 可変ワードに依存しない部分を事前計算したSHA-1中間状態（ミッドステート）
+/// 
+/// 統合探索ではdata[9]以外のワードがTimer0/VCountと日付ごとに固定されるため、
+/// data[9]を0とみなして展開した80ワードのメッセージスケジュールと、
+/// data[9]を参照する前のラウンド0-8実行後の作業変数をキャッシュしておく。
+/// 毎秒の計算ではdata[9]に依存するスケジュールワード（`VARIABLE_SCHEDULE_WORDS_EARLY`と
+/// `VARIABLE_SCHEDULE_FULL_START`以降）だけを再計算し、それ以外はキャッシュをそのまま使う。
+#[derive(Debug, Clone, Copy)]
+pub struct Sha1Prefix {
+    /// ラウンド0-8実行後の作業変数 (a, b, c, d, e)
+    pub state: [u32; 5],
+    /// data[9]を0として展開したメッセージスケジュール
+    pub schedule: [u32; 80],
+}
+
+impl Sha1Prefix {
+    /// 16ワードメッセージから中間状態を構築（message[9]の値は無視される）
+    pub fn new(message: &[u32; 16]) -> Sha1Prefix {
+        let mut schedule = [0u32; 80];
+        schedule[..16].copy_from_slice(message);
+        schedule[VARIABLE_WORD_INDEX] = 0;
+        expand_message_schedule(&mut schedule);
+
+        let mut state = SHA1_INITIAL_STATE;
+        for &w_val in &schedule[..VARIABLE_WORD_INDEX] {
+            sha1_step(&mut state, choice, 0x5A827999, w_val);
+        }
+
+        Sha1Prefix { state, schedule }
+    }
+}
+
+/// W[16..31]のうち可変ワード（data[9]）に依存するメッセージスケジュールの位置（昇順）
+/// W[16], W[18], W[19], W[21], W[22], W[24], W[27], W[30]はdata[9]を含まないため
+/// 中間状態のスケジュールをそのまま使える
+pub const VARIABLE_SCHEDULE_WORDS_EARLY: [usize; 7] = [17, 20, 23, 25, 26, 28, 29];
+
+/// この位置以降のメッセージスケジュールはすべて可変ワードに依存する
+pub const VARIABLE_SCHEDULE_FULL_START: usize = 31;
+
+/// 事前計算済み中間状態と可変ワードからSHA-1を計算
+/// `calculate_pokemon_sha1`でmessage[9]を`variable_word`に置き換えた場合と同じ結果を返す
+#[inline]
+pub fn calculate_pokemon_sha1_with_prefix(prefix: &Sha1Prefix, variable_word: u32) -> (u32, u32, u32, u32, u32) {
+    // data[9]に依存するスケジュールワードのみ再計算する
+    let mut w = prefix.schedule;
+    w[VARIABLE_WORD_INDEX] = variable_word;
+    for i in VARIABLE_SCHEDULE_WORDS_EARLY {
+        w[i] = schedule_word(&w, i);
+    }
+    for i in VARIABLE_SCHEDULE_FULL_START..80 {
+        w[i] = schedule_word(&w, i);
+    }
+
+    // ラウンド9-79（ラウンド関数ごとに区切って分岐をなくす）
+    let mut state = prefix.state;
+    for &w_val in &w[VARIABLE_WORD_INDEX..20] {
+        sha1_step(&mut state, choice, 0x5A827999, w_val);
+    }
+    for &w_val in &w[20..40] {
+        sha1_step(&mut state, parity, 0x6ED9EBA1, w_val);
+    }
+    for &w_val in &w[40..60] {
+        sha1_step(&mut state, majority, 0x8F1BBCDC, w_val);
+    }
+    for &w_val in &w[60..80] {
+        sha1_step(&mut state, parity, 0xCA62C1D6, w_val);
+    }
+
+    (
+        SHA1_INITIAL_STATE[0].wrapping_add(state[0]),
+        SHA1_INITIAL_STATE[1].wrapping_add(state[1]),
+        SHA1_INITIAL_STATE[2].wrapping_add(state[2]),
+        SHA1_INITIAL_STATE[3].wrapping_add(state[3]),
+        SHA1_INITIAL_STATE[4].wrapping_add(state[4]),
+    )
+}
+
+/// SHA-1初期値 (H0-H4)
+const SHA1_INITIAL_STATE: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+/// メッセージスケジュールの残り64ワードを展開
+#[inline(always)]
+fn expand_message_schedule(w: &mut [u32; 80]) {
+    for i in 16..80 {
+        w[i] = schedule_word(w, i);
+    }
+}
+
+/// スケジュールワード計算: W[i] = rotl(W[i-3] ^ W[i-8] ^ W[i-14] ^ W[i-16], 1)
+#[inline(always)]
+fn schedule_word(w: &[u32; 80], i: usize) -> u32 {
+    left_rotate(w[i-3] ^ w[i-8] ^ w[i-14] ^ w[i-16], 1)
+}
+
+/// SHA-1の1ラウンド処理（ラウンド関数と定数を指定）
+#[inline(always)]
+fn sha1_step(state: &mut [u32; 5], f: fn(u32, u32, u32) -> u32, k: u32, w_val: u32) {
+    let [a, b, c, d, e] = *state;
+    let temp = left_rotate(a, 5)
+        .wrapping_add(f(b, c, d))
+        .wrapping_add(e)
+        .wrapping_add(k)
+        .wrapping_add(w_val);
+
+    *state = [temp, a, left_rotate(b, 30), c, d];
+}
+
+/// ポケモンBW/BW2用LCG初期シード
+/// SHA-1ハッシュ値の先頭64bitをリトルエンディアンとして解釈した値（LCGを進める前の64bitシード）
+#[inline]
+pub fn calculate_pokemon_lcg_seed_from_hash(h0: u32, h1: u32) -> u64 {
+    let h0_le = swap_bytes_32(h0) as u64;
+    let h1_le = swap_bytes_32(h1) as u64;
+    (h1_le << 32) | h0_le
+}
+
 /// ポケモンBW/BW2用LCG計算
 /// SHA-1ハッシュ値からTypeScript版と同じ方式で最終seedを計算
 pub fn calculate_pokemon_seed_from_hash(h0: u32, h1: u32) -> u32 {
-    // TypeScript版と同じバイトスワップとLCG計算
-    let h0_le = swap_bytes_32(h0) as u64;
-    let h1_le = swap_bytes_32(h1) as u64;
-    
-    // 64bit値を構築
-    let lcg_seed = (h1_le << 32) | h0_le;
+    // TypeScript版と同じバイトスワップで64bit値を構築
+    let lcg_seed = calculate_pokemon_lcg_seed_from_hash(h0, h1);
     
     // 64bit LCG演算
     let multiplier = 0x5D588B656C078965u64;
@@ -108,7 +224,7 @@ pub fn majority(x: u32, y: u32, z: u32) -> u32 {
 /// 左回転関数
 #[inline]
 pub fn left_rotate(value: u32, amount: u32) -> u32 {
-    (value << amount) | (value >> (32 - amount))
+    value.rotate_left(amount)
 }
 
 /// バイトスワップ関数（32bit）
@@ -1,160 +1,128 @@
 /// ポケモンBW/BW2特化SHA-1のSIMD実装
-/// WebAssembly SIMD命令を使用して4つのメッセージを並列処理
+/// WebAssembly SIMD命令、またはx86_64のSSE2/AVX2命令を使用して複数のメッセージを並列処理
 
 #[cfg(target_arch = "wasm32")]
 use core::arch::wasm32::*;
+use crate::sha1::Sha1Prefix;
+#[cfg(target_arch = "wasm32")]
+use crate::sha1::{VARIABLE_SCHEDULE_FULL_START, VARIABLE_SCHEDULE_WORDS_EARLY, VARIABLE_WORD_INDEX};
+
+/// 事前計算済み中間状態を使い、8つの可変ワード（data[9]）についてSHA-1を並列計算
+/// x86_64ではAVX2の有無を実行時に検出し、利用できない場合は4レーン実装を2回呼び出す
+#[cfg(target_arch = "x86_64")]
+pub fn calculate_pokemon_sha1_simd_x8_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 8]) -> [u32; 40] {
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2が利用可能であることを実行時に確認済み
+        unsafe { x86::sha1_x8_avx2_with_prefix(prefix, variable_words) }
+    } else {
+        calculate_pokemon_sha1_simd_x8_with_prefix_split(prefix, variable_words)
+    }
+}
+
+/// 事前計算済み中間状態を使い、8つの可変ワード（data[9]）についてSHA-1を並列計算（4レーン実装×2）
+#[cfg(not(target_arch = "x86_64"))]
+pub fn calculate_pokemon_sha1_simd_x8_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 8]) -> [u32; 40] {
+    calculate_pokemon_sha1_simd_x8_with_prefix_split(prefix, variable_words)
+}
+
+/// 8つの可変ワードを4つずつに分けて処理
+#[inline]
+fn calculate_pokemon_sha1_simd_x8_with_prefix_split(prefix: &Sha1Prefix, variable_words: &[u32; 8]) -> [u32; 40] {
+    let mut results = [0u32; 40];
+    for half in 0..2 {
+        let mut batch = [0u32; 4];
+        batch.copy_from_slice(&variable_words[half * 4..(half + 1) * 4]);
+        results[half * 20..(half + 1) * 20].copy_from_slice(&calculate_pokemon_sha1_simd_with_prefix(prefix, &batch));
+    }
+    results
+}
 
-/// 非WASM環境用のフォールバック実装
-#[cfg(not(target_arch = "wasm32"))]
-pub fn calculate_pokemon_sha1_simd(messages: &[u32; 64]) -> [u32; 20] {
+/// 事前計算済み中間状態を使い、4つの可変ワードについてSHA-1を並列計算（SSE2）
+#[cfg(target_arch = "x86_64")]
+pub fn calculate_pokemon_sha1_simd_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 4]) -> [u32; 20] {
+    // SAFETY: SSE2はx86_64のベースライン命令セットであり常に利用可能
+    unsafe { x86::sha1_x4_sse2_with_prefix(prefix, variable_words) }
+}
+
+/// 事前計算済み中間状態を使い、4つの可変ワードについてSHA-1を計算（非WASM・非x86_64環境用フォールバック）
+#[cfg(not(any(target_arch = "wasm32", target_arch = "x86_64")))]
+pub fn calculate_pokemon_sha1_simd_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 4]) -> [u32; 20] {
     let mut results = [0u32; 20];
-    
-    // 4組のメッセージを通常のSHA-1で処理
-    for i in 0..4 {
-        let start_idx = i * 16;
-        let mut message = [0u32; 16];
-        message.copy_from_slice(&messages[start_idx..start_idx + 16]);
-        
-        let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1(&message);
-        
-        let base_idx = i * 5;
-        results[base_idx] = h0;
-        results[base_idx + 1] = h1;
-        results[base_idx + 2] = h2;
-        results[base_idx + 3] = h3;
-        results[base_idx + 4] = h4;
+    for (i, &word) in variable_words.iter().enumerate() {
+        let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1_with_prefix(prefix, word);
+        results[i * 5..i * 5 + 5].copy_from_slice(&[h0, h1, h2, h3, h4]);
     }
-    
     results
 }
 
-/// WASM環境用の本格的SIMD実装
-/// 4組の16ワードメッセージを並列処理し、4組のハッシュ値を返す
+/// WASM環境用: 事前計算済み中間状態を使い、4つの可変ワードについてSHA-1を並列計算
 #[cfg(target_arch = "wasm32")]
-pub fn calculate_pokemon_sha1_simd(messages: &[u32; 64]) -> [u32; 20] {
-    // SHA-1初期値をSIMDベクトルとして準備
-    let h0_init = u32x4_splat(0x67452301);
-    let h1_init = u32x4_splat(0xEFCDAB89);
-    let h2_init = u32x4_splat(0x98BADCFE);
-    let h3_init = u32x4_splat(0x10325476);
-    let h4_init = u32x4_splat(0xC3D2E1F0);
-
-    // 80ワードのメッセージスケジュール配列（4組分並列）
+pub fn calculate_pokemon_sha1_simd_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 4]) -> [u32; 20] {
+    // data[9]に依存しないスケジュール（W[31]未満）は中間状態のものを全レーンへ複製し、依存するワードのみ再計算する
     let mut w = [u32x4_splat(0); 80];
-    
-    // 最初の16ワードを4組分並列でロード
-    for i in 0..16 {
-        let lane0 = messages[i];           // 1組目
-        let lane1 = messages[16 + i];      // 2組目
-        let lane2 = messages[32 + i];      // 3組目
-        let lane3 = messages[48 + i];      // 4組目
-        
-        w[i] = u32x4(lane0, lane1, lane2, lane3);
+    for (word, &fixed) in w.iter_mut().zip(prefix.schedule.iter()).take(VARIABLE_SCHEDULE_FULL_START) {
+        *word = u32x4_splat(fixed);
     }
-    
-    // 残りの64ワードを計算（メッセージ拡張）
-    for i in 16..80 {
-        let w3 = w[i-3];
-        let w8 = w[i-8];
-        let w14 = w[i-14];
-        let w16 = w[i-16];
-        
-        // w[i] = left_rotate(w[i-3] ^ w[i-8] ^ w[i-14] ^ w[i-16], 1)
-        let xor_result = v128_xor(v128_xor(v128_xor(w3, w8), w14), w16);
-        w[i] = simd_left_rotate(xor_result, 1);
+    w[VARIABLE_WORD_INDEX] = u32x4(variable_words[0], variable_words[1], variable_words[2], variable_words[3]);
+    for i in VARIABLE_SCHEDULE_WORDS_EARLY {
+        w[i] = simd_schedule_word(&w, i);
     }
-    
-    // メイン処理ループ用変数
-    let mut a = h0_init;
-    let mut b = h1_init;
-    let mut c = h2_init;
-    let mut d = h3_init;
-    let mut e = h4_init;
-
-    // ラウンド定数
-    let k1 = u32x4_splat(0x5A827999);
-    let k2 = u32x4_splat(0x6ED9EBA1);
-    let k3 = u32x4_splat(0x8F1BBCDC);
-    let k4 = u32x4_splat(0xCA62C1D6);
-
-    // 80ラウンドの処理
-    for i in 0..80 {
-        let (f, k) = match i {
-            0..=19 => (simd_choice(b, c, d), k1),
-            20..=39 => (simd_parity(b, c, d), k2),
-            40..=59 => (simd_majority(b, c, d), k3),
-            60..=79 => (simd_parity(b, c, d), k4),
-            _ => unreachable!(),
-        };
-        
-        // temp = left_rotate(a, 5) + f + e + k + w[i]
-        let temp = u32x4_add(
-            u32x4_add(
-                u32x4_add(
-                    u32x4_add(simd_left_rotate(a, 5), f),
-                    e
-                ),
-                k
-            ),
-            w[i]
-        );
-        
-        e = d;
-        d = c;
-        c = simd_left_rotate(b, 30);
-        b = a;
-        a = temp;
+    for i in VARIABLE_SCHEDULE_FULL_START..80 {
+        w[i] = simd_schedule_word(&w, i);
     }
-    
+
+    // 事前計算済みの作業変数から再開し、ラウンド9-79をラウンド関数ごとに区切って処理
+    let mut state = prefix.state.map(|v| u32x4_splat(v));
+    for &w_val in &w[VARIABLE_WORD_INDEX..20] {
+        simd_step(&mut state, simd_choice, u32x4_splat(0x5A827999), w_val);
+    }
+    for &w_val in &w[20..40] {
+        simd_step(&mut state, simd_parity, u32x4_splat(0x6ED9EBA1), w_val);
+    }
+    for &w_val in &w[40..60] {
+        simd_step(&mut state, simd_majority, u32x4_splat(0x8F1BBCDC), w_val);
+    }
+    for &w_val in &w[60..80] {
+        simd_step(&mut state, simd_parity, u32x4_splat(0xCA62C1D6), w_val);
+    }
+    let [a, b, c, d, e] = state;
+
     // 最終ハッシュ値計算
-    let h0_final = u32x4_add(h0_init, a);
-    let h1_final = u32x4_add(h1_init, b);
-    let h2_final = u32x4_add(h2_init, c);
-    let h3_final = u32x4_add(h3_init, d);
-    let h4_final = u32x4_add(h4_init, e);
-    
-    // 結果を配列に展開
-    let mut results = [0u32; 20];
-    
+    let finals = [
+        u32x4_add(u32x4_splat(0x67452301), a),
+        u32x4_add(u32x4_splat(0xEFCDAB89), b),
+        u32x4_add(u32x4_splat(0x98BADCFE), c),
+        u32x4_add(u32x4_splat(0x10325476), d),
+        u32x4_add(u32x4_splat(0xC3D2E1F0), e),
+    ];
+
     // 各レーンから値を抽出
-    for lane in 0..4 {
-        let base_idx = lane * 5;
-        match lane {
-            0 => {
-                results[base_idx] = u32x4_extract_lane::<0>(h0_final);
-                results[base_idx + 1] = u32x4_extract_lane::<0>(h1_final);
-                results[base_idx + 2] = u32x4_extract_lane::<0>(h2_final);
-                results[base_idx + 3] = u32x4_extract_lane::<0>(h3_final);
-                results[base_idx + 4] = u32x4_extract_lane::<0>(h4_final);
-            },
-            1 => {
-                results[base_idx] = u32x4_extract_lane::<1>(h0_final);
-                results[base_idx + 1] = u32x4_extract_lane::<1>(h1_final);
-                results[base_idx + 2] = u32x4_extract_lane::<1>(h2_final);
-                results[base_idx + 3] = u32x4_extract_lane::<1>(h3_final);
-                results[base_idx + 4] = u32x4_extract_lane::<1>(h4_final);
-            },
-            2 => {
-                results[base_idx] = u32x4_extract_lane::<2>(h0_final);
-                results[base_idx + 1] = u32x4_extract_lane::<2>(h1_final);
-                results[base_idx + 2] = u32x4_extract_lane::<2>(h2_final);
-                results[base_idx + 3] = u32x4_extract_lane::<2>(h3_final);
-                results[base_idx + 4] = u32x4_extract_lane::<2>(h4_final);
-            },
-            3 => {
-                results[base_idx] = u32x4_extract_lane::<3>(h0_final);
-                results[base_idx + 1] = u32x4_extract_lane::<3>(h1_final);
-                results[base_idx + 2] = u32x4_extract_lane::<3>(h2_final);
-                results[base_idx + 3] = u32x4_extract_lane::<3>(h3_final);
-                results[base_idx + 4] = u32x4_extract_lane::<3>(h4_final);
-            },
-            _ => unreachable!(),
-        }
+    let mut results = [0u32; 20];
+    for (j, &h) in finals.iter().enumerate() {
+        results[j] = u32x4_extract_lane::<0>(h);
+        results[5 + j] = u32x4_extract_lane::<1>(h);
+        results[10 + j] = u32x4_extract_lane::<2>(h);
+        results[15 + j] = u32x4_extract_lane::<3>(h);
     }
-    
     results
 }
 
+/// SIMD版スケジュールワード計算: W[i] = rotl(W[i-3] ^ W[i-8] ^ W[i-14] ^ W[i-16], 1)
+#[cfg(target_arch = "wasm32")]
+#[inline]
+fn simd_schedule_word(w: &[v128; 80], i: usize) -> v128 {
+    simd_left_rotate(v128_xor(v128_xor(v128_xor(w[i-3], w[i-8]), w[i-14]), w[i-16]), 1)
+}
+
+/// SIMD版1ラウンド処理（ラウンド関数と定数を指定）
+#[cfg(target_arch = "wasm32")]
+#[inline(always)]
+fn simd_step(state: &mut [v128; 5], f: fn(v128, v128, v128) -> v128, k: v128, w_val: v128) {
+    let [a, b, c, d, e] = *state;
+    let temp = u32x4_add(u32x4_add(u32x4_add(u32x4_add(simd_left_rotate(a, 5), f(b, c, d)), e), k), w_val);
+    *state = [temp, a, simd_left_rotate(b, 30), c, d];
+}
+
 /// SIMD版choice関数: (x & y) | (!x & z)
 #[cfg(target_arch = "wasm32")]
 #[inline]
@@ -184,3 +152,509 @@ fn simd_left_rotate(value: v128, amount: u32) -> v128 {
     let right = u32x4_shr(value, 32 - amount);
     v128_or(left, right)
 }
+
+/// x86_64向けSIMD実装（SSE2: 4レーン, AVX2: 8レーン）
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::*;
+    use crate::sha1::{Sha1Prefix, VARIABLE_SCHEDULE_FULL_START, VARIABLE_SCHEDULE_WORDS_EARLY, VARIABLE_WORD_INDEX};
+
+    /// SHA-1初期値
+    pub(super) const H_INIT: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    /// ラウンド定数
+    const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
+
+    /// SSE2版: 事前計算済み中間状態から4つの可変ワードを並列処理
+    #[target_feature(enable = "sse2")]
+    pub fn sha1_x4_sse2_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 4]) -> [u32; 20] {
+        // data[9]に依存しないスケジュール（W[31]未満）は中間状態のものを全レーンへ複製し、依存するワードのみ再計算する
+        let mut w = [_mm_setzero_si128(); 80];
+        for (word, &fixed) in w.iter_mut().zip(prefix.schedule.iter()).take(VARIABLE_SCHEDULE_FULL_START) {
+            *word = _mm_set1_epi32(fixed as i32);
+        }
+        w[VARIABLE_WORD_INDEX] = _mm_set_epi32(
+            variable_words[3] as i32,
+            variable_words[2] as i32,
+            variable_words[1] as i32,
+            variable_words[0] as i32,
+        );
+        for i in VARIABLE_SCHEDULE_WORDS_EARLY {
+            w[i] = sse2_schedule_word(&w, i);
+        }
+        for i in VARIABLE_SCHEDULE_FULL_START..80 {
+            w[i] = sse2_schedule_word(&w, i);
+        }
+
+        let h_init = H_INIT.map(|h| _mm_set1_epi32(h as i32));
+        let state = sse2_compress(prefix.state.map(|v| _mm_set1_epi32(v as i32)), &w, VARIABLE_WORD_INDEX);
+        sse2_finalize(h_init, state)
+    }
+
+    /// SSE2版スケジュールワード計算: W[i] = rotl(W[i-3] ^ W[i-8] ^ W[i-14] ^ W[i-16], 1)
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) fn sse2_schedule_word(w: &[__m128i; 80], i: usize) -> __m128i {
+        let xor_result = _mm_xor_si128(_mm_xor_si128(_mm_xor_si128(w[i - 3], w[i - 8]), w[i - 14]), w[i - 16]);
+        sse2_left_rotate::<1, 31>(xor_result)
+    }
+
+    /// SSE2版圧縮関数（start_roundから79ラウンドまで、start_roundは20未満）
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) fn sse2_compress(state: [__m128i; 5], w: &[__m128i; 80], start_round: usize) -> [__m128i; 5] {
+        let k = K.map(|k| _mm_set1_epi32(k as i32));
+        let mut state = state;
+
+        // ラウンド関数ごとに区切って分岐をなくす
+        for &w_val in &w[start_round..20] {
+            sse2_step::<0>(&mut state, k[0], w_val);
+        }
+        for &w_val in &w[20..40] {
+            sse2_step::<1>(&mut state, k[1], w_val);
+        }
+        for &w_val in &w[40..60] {
+            sse2_step::<2>(&mut state, k[2], w_val);
+        }
+        for &w_val in &w[60..80] {
+            sse2_step::<1>(&mut state, k[3], w_val);
+        }
+
+        state
+    }
+
+    /// SSE2版1ラウンド処理（ROUND_FN: 0=choice, 1=parity, 2=majority）
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    fn sse2_step<const ROUND_FN: u8>(state: &mut [__m128i; 5], k: __m128i, w_val: __m128i) {
+        let [a, b, c, d, e] = *state;
+        let f = match ROUND_FN {
+            0 => sse2_choice(b, c, d),
+            1 => sse2_parity(b, c, d),
+            _ => sse2_majority(b, c, d),
+        };
+        let temp = _mm_add_epi32(_mm_add_epi32(_mm_add_epi32(_mm_add_epi32(sse2_left_rotate::<5, 27>(a), f), e), k), w_val);
+        *state = [temp, a, sse2_left_rotate::<30, 2>(b), c, d];
+    }
+
+    /// SSE2版最終ハッシュ値計算（各レーンから展開）
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) fn sse2_finalize(h_init: [__m128i; 5], state: [__m128i; 5]) -> [u32; 20] {
+        let mut lanes = [[0u32; 4]; 5];
+        for j in 0..5 {
+            let h = _mm_add_epi32(h_init[j], state[j]);
+            // SAFETY: lanes[j]は16バイトの書き込み可能領域（アラインメント不要のstoreu）
+            unsafe { _mm_storeu_si128(lanes[j].as_mut_ptr() as *mut __m128i, h) };
+        }
+        let mut results = [0u32; 20];
+        for lane in 0..4 {
+            for j in 0..5 {
+                results[lane * 5 + j] = lanes[j][lane];
+            }
+        }
+        results
+    }
+
+    /// SSE2版choice関数: (x & y) | (!x & z)
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    fn sse2_choice(x: __m128i, y: __m128i, z: __m128i) -> __m128i {
+        _mm_or_si128(_mm_and_si128(x, y), _mm_andnot_si128(x, z))
+    }
+
+    /// SSE2版parity関数: x ^ y ^ z
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    fn sse2_parity(x: __m128i, y: __m128i, z: __m128i) -> __m128i {
+        _mm_xor_si128(_mm_xor_si128(x, y), z)
+    }
+
+    /// SSE2版majority関数: (x & y) | (x & z) | (y & z)
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    fn sse2_majority(x: __m128i, y: __m128i, z: __m128i) -> __m128i {
+        _mm_or_si128(_mm_or_si128(_mm_and_si128(x, y), _mm_and_si128(x, z)), _mm_and_si128(y, z))
+    }
+
+    /// SSE2版左回転関数（LEFT + RIGHT = 32）
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    fn sse2_left_rotate<const LEFT: i32, const RIGHT: i32>(value: __m128i) -> __m128i {
+        _mm_or_si128(_mm_slli_epi32::<LEFT>(value), _mm_srli_epi32::<RIGHT>(value))
+    }
+
+    /// AVX2版: 事前計算済み中間状態から8つの可変ワードを並列処理
+    #[target_feature(enable = "avx2")]
+    pub fn sha1_x8_avx2_with_prefix(prefix: &Sha1Prefix, variable_words: &[u32; 8]) -> [u32; 40] {
+        // data[9]に依存しないスケジュール（W[31]未満）は中間状態のものを全レーンへ複製し、依存するワードのみ再計算する
+        let mut w = [_mm256_setzero_si256(); 80];
+        for (word, &fixed) in w.iter_mut().zip(prefix.schedule.iter()).take(VARIABLE_SCHEDULE_FULL_START) {
+            *word = _mm256_set1_epi32(fixed as i32);
+        }
+        w[VARIABLE_WORD_INDEX] = _mm256_set_epi32(
+            variable_words[7] as i32,
+            variable_words[6] as i32,
+            variable_words[5] as i32,
+            variable_words[4] as i32,
+            variable_words[3] as i32,
+            variable_words[2] as i32,
+            variable_words[1] as i32,
+            variable_words[0] as i32,
+        );
+        for i in VARIABLE_SCHEDULE_WORDS_EARLY {
+            w[i] = avx2_schedule_word(&w, i);
+        }
+        for i in VARIABLE_SCHEDULE_FULL_START..80 {
+            w[i] = avx2_schedule_word(&w, i);
+        }
+
+        let h_init = H_INIT.map(|h| _mm256_set1_epi32(h as i32));
+        let state = avx2_compress(prefix.state.map(|v| _mm256_set1_epi32(v as i32)), &w, VARIABLE_WORD_INDEX);
+        avx2_finalize(h_init, state)
+    }
+
+    /// AVX2版スケジュールワード計算: W[i] = rotl(W[i-3] ^ W[i-8] ^ W[i-14] ^ W[i-16], 1)
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) fn avx2_schedule_word(w: &[__m256i; 80], i: usize) -> __m256i {
+        let xor_result = _mm256_xor_si256(_mm256_xor_si256(_mm256_xor_si256(w[i - 3], w[i - 8]), w[i - 14]), w[i - 16]);
+        avx2_left_rotate::<1, 31>(xor_result)
+    }
+
+    /// AVX2版圧縮関数（start_roundから79ラウンドまで、start_roundは20未満）
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) fn avx2_compress(state: [__m256i; 5], w: &[__m256i; 80], start_round: usize) -> [__m256i; 5] {
+        let k = K.map(|k| _mm256_set1_epi32(k as i32));
+        let mut state = state;
+
+        // ラウンド関数ごとに区切って分岐をなくす
+        for &w_val in &w[start_round..20] {
+            avx2_step::<0>(&mut state, k[0], w_val);
+        }
+        for &w_val in &w[20..40] {
+            avx2_step::<1>(&mut state, k[1], w_val);
+        }
+        for &w_val in &w[40..60] {
+            avx2_step::<2>(&mut state, k[2], w_val);
+        }
+        for &w_val in &w[60..80] {
+            avx2_step::<1>(&mut state, k[3], w_val);
+        }
+
+        state
+    }
+
+    /// AVX2版1ラウンド処理（ROUND_FN: 0=choice, 1=parity, 2=majority）
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn avx2_step<const ROUND_FN: u8>(state: &mut [__m256i; 5], k: __m256i, w_val: __m256i) {
+        let [a, b, c, d, e] = *state;
+        let f = match ROUND_FN {
+            0 => avx2_choice(b, c, d),
+            1 => avx2_parity(b, c, d),
+            _ => avx2_majority(b, c, d),
+        };
+        let temp = _mm256_add_epi32(_mm256_add_epi32(_mm256_add_epi32(_mm256_add_epi32(avx2_left_rotate::<5, 27>(a), f), e), k), w_val);
+        *state = [temp, a, avx2_left_rotate::<30, 2>(b), c, d];
+    }
+
+    /// AVX2版最終ハッシュ値計算（各レーンから展開）
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) fn avx2_finalize(h_init: [__m256i; 5], state: [__m256i; 5]) -> [u32; 40] {
+        let mut lanes = [[0u32; 8]; 5];
+        for j in 0..5 {
+            let h = _mm256_add_epi32(h_init[j], state[j]);
+            // SAFETY: lanes[j]は32バイトの書き込み可能領域（アラインメント不要のstoreu）
+            unsafe { _mm256_storeu_si256(lanes[j].as_mut_ptr() as *mut __m256i, h) };
+        }
+        let mut results = [0u32; 40];
+        for lane in 0..8 {
+            for j in 0..5 {
+                results[lane * 5 + j] = lanes[j][lane];
+            }
+        }
+        results
+    }
+
+    /// AVX2版choice関数: (x & y) | (!x & z)
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn avx2_choice(x: __m256i, y: __m256i, z: __m256i) -> __m256i {
+        _mm256_or_si256(_mm256_and_si256(x, y), _mm256_andnot_si256(x, z))
+    }
+
+    /// AVX2版parity関数: x ^ y ^ z
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn avx2_parity(x: __m256i, y: __m256i, z: __m256i) -> __m256i {
+        _mm256_xor_si256(_mm256_xor_si256(x, y), z)
+    }
+
+    /// AVX2版majority関数: (x & y) | (x & z) | (y & z)
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn avx2_majority(x: __m256i, y: __m256i, z: __m256i) -> __m256i {
+        _mm256_or_si256(_mm256_or_si256(_mm256_and_si256(x, y), _mm256_and_si256(x, z)), _mm256_and_si256(y, z))
+    }
+
+    /// AVX2版左回転関数（LEFT + RIGHT = 32）
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn avx2_left_rotate<const LEFT: i32, const RIGHT: i32>(value: __m256i) -> __m256i {
+        _mm256_or_si256(_mm256_slli_epi32::<LEFT>(value), _mm256_srli_epi32::<RIGHT>(value))
+    }
+}
+
+/// 完全メッセージ版（16ワードのメッセージをそのまま処理する）
+/// 探索は中間状態版のみを使うため、テストでの照合とベンチマークでの比較対象に限って残す
+#[cfg(any(test, feature = "bench"))]
+pub mod full_message {
+    #[cfg(target_arch = "wasm32")]
+    use core::arch::wasm32::*;
+    #[cfg(target_arch = "wasm32")]
+    use super::{simd_choice, simd_parity, simd_majority, simd_left_rotate};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    #[cfg(target_arch = "x86_64")]
+    use super::x86::{H_INIT, sse2_schedule_word, sse2_compress, sse2_finalize, avx2_schedule_word, avx2_compress, avx2_finalize};
+
+    /// 8組の16ワードメッセージを並列処理し、8組のハッシュ値を返す
+    /// x86_64ではAVX2の有無を実行時に検出し、利用できない場合は4レーン実装を2回呼び出す
+    #[cfg(target_arch = "x86_64")]
+    pub fn calculate_pokemon_sha1_simd_x8(messages: &[u32; 128]) -> [u32; 40] {
+        if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2が利用可能であることを実行時に確認済み
+            unsafe { sha1_x8_avx2(messages) }
+        } else {
+            calculate_pokemon_sha1_simd_x8_split(messages)
+        }
+    }
+
+    /// 8組の16ワードメッセージを並列処理し、8組のハッシュ値を返す（4レーン実装×2）
+    #[cfg(not(target_arch = "x86_64"))]
+    pub fn calculate_pokemon_sha1_simd_x8(messages: &[u32; 128]) -> [u32; 40] {
+        calculate_pokemon_sha1_simd_x8_split(messages)
+    }
+
+    /// 8組のメッセージを4組ずつに分けて処理
+    #[inline]
+    fn calculate_pokemon_sha1_simd_x8_split(messages: &[u32; 128]) -> [u32; 40] {
+        let mut results = [0u32; 40];
+        for half in 0..2 {
+            let mut batch = [0u32; 64];
+            batch.copy_from_slice(&messages[half * 64..(half + 1) * 64]);
+            results[half * 20..(half + 1) * 20].copy_from_slice(&calculate_pokemon_sha1_simd(&batch));
+        }
+        results
+    }
+
+    /// x86_64環境用のSSE2実装（x86_64では常に利用可能）
+    #[cfg(target_arch = "x86_64")]
+    pub fn calculate_pokemon_sha1_simd(messages: &[u32; 64]) -> [u32; 20] {
+        // SAFETY: SSE2はx86_64のベースライン命令セットであり常に利用可能
+        unsafe { sha1_x4_sse2(messages) }
+    }
+
+    /// 非WASM・非x86_64環境用のフォールバック実装
+    #[cfg(not(any(target_arch = "wasm32", target_arch = "x86_64")))]
+    pub fn calculate_pokemon_sha1_simd(messages: &[u32; 64]) -> [u32; 20] {
+        let mut results = [0u32; 20];
+    
+        // 4組のメッセージを通常のSHA-1で処理
+        for i in 0..4 {
+            let start_idx = i * 16;
+            let mut message = [0u32; 16];
+            message.copy_from_slice(&messages[start_idx..start_idx + 16]);
+        
+            let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1(&message);
+        
+            let base_idx = i * 5;
+            results[base_idx] = h0;
+            results[base_idx + 1] = h1;
+            results[base_idx + 2] = h2;
+            results[base_idx + 3] = h3;
+            results[base_idx + 4] = h4;
+        }
+    
+        results
+    }
+
+    /// WASM環境用の本格的SIMD実装
+    /// 4組の16ワードメッセージを並列処理し、4組のハッシュ値を返す
+    #[cfg(target_arch = "wasm32")]
+    pub fn calculate_pokemon_sha1_simd(messages: &[u32; 64]) -> [u32; 20] {
+        // SHA-1初期値をSIMDベクトルとして準備
+        let h0_init = u32x4_splat(0x67452301);
+        let h1_init = u32x4_splat(0xEFCDAB89);
+        let h2_init = u32x4_splat(0x98BADCFE);
+        let h3_init = u32x4_splat(0x10325476);
+        let h4_init = u32x4_splat(0xC3D2E1F0);
+
+        // 80ワードのメッセージスケジュール配列（4組分並列）
+        let mut w = [u32x4_splat(0); 80];
+    
+        // 最初の16ワードを4組分並列でロード
+        for i in 0..16 {
+            let lane0 = messages[i];           // 1組目
+            let lane1 = messages[16 + i];      // 2組目
+            let lane2 = messages[32 + i];      // 3組目
+            let lane3 = messages[48 + i];      // 4組目
+        
+            w[i] = u32x4(lane0, lane1, lane2, lane3);
+        }
+    
+        // 残りの64ワードを計算（メッセージ拡張）
+        for i in 16..80 {
+            let w3 = w[i-3];
+            let w8 = w[i-8];
+            let w14 = w[i-14];
+            let w16 = w[i-16];
+        
+            // w[i] = left_rotate(w[i-3] ^ w[i-8] ^ w[i-14] ^ w[i-16], 1)
+            let xor_result = v128_xor(v128_xor(v128_xor(w3, w8), w14), w16);
+            w[i] = simd_left_rotate(xor_result, 1);
+        }
+    
+        // メイン処理ループ用変数
+        let mut a = h0_init;
+        let mut b = h1_init;
+        let mut c = h2_init;
+        let mut d = h3_init;
+        let mut e = h4_init;
+
+        // ラウンド定数
+        let k1 = u32x4_splat(0x5A827999);
+        let k2 = u32x4_splat(0x6ED9EBA1);
+        let k3 = u32x4_splat(0x8F1BBCDC);
+        let k4 = u32x4_splat(0xCA62C1D6);
+
+        // 80ラウンドの処理
+        for i in 0..80 {
+            let (f, k) = match i {
+                0..=19 => (simd_choice(b, c, d), k1),
+                20..=39 => (simd_parity(b, c, d), k2),
+                40..=59 => (simd_majority(b, c, d), k3),
+                60..=79 => (simd_parity(b, c, d), k4),
+                _ => unreachable!(),
+            };
+        
+            // temp = left_rotate(a, 5) + f + e + k + w[i]
+            let temp = u32x4_add(
+                u32x4_add(
+                    u32x4_add(
+                        u32x4_add(simd_left_rotate(a, 5), f),
+                        e
+                    ),
+                    k
+                ),
+                w[i]
+            );
+        
+            e = d;
+            d = c;
+            c = simd_left_rotate(b, 30);
+            b = a;
+            a = temp;
+        }
+    
+        // 最終ハッシュ値計算
+        let h0_final = u32x4_add(h0_init, a);
+        let h1_final = u32x4_add(h1_init, b);
+        let h2_final = u32x4_add(h2_init, c);
+        let h3_final = u32x4_add(h3_init, d);
+        let h4_final = u32x4_add(h4_init, e);
+    
+        // 結果を配列に展開
+        let mut results = [0u32; 20];
+    
+        // 各レーンから値を抽出
+        for lane in 0..4 {
+            let base_idx = lane * 5;
+            match lane {
+                0 => {
+                    results[base_idx] = u32x4_extract_lane::<0>(h0_final);
+                    results[base_idx + 1] = u32x4_extract_lane::<0>(h1_final);
+                    results[base_idx + 2] = u32x4_extract_lane::<0>(h2_final);
+                    results[base_idx + 3] = u32x4_extract_lane::<0>(h3_final);
+                    results[base_idx + 4] = u32x4_extract_lane::<0>(h4_final);
+                },
+                1 => {
+                    results[base_idx] = u32x4_extract_lane::<1>(h0_final);
+                    results[base_idx + 1] = u32x4_extract_lane::<1>(h1_final);
+                    results[base_idx + 2] = u32x4_extract_lane::<1>(h2_final);
+                    results[base_idx + 3] = u32x4_extract_lane::<1>(h3_final);
+                    results[base_idx + 4] = u32x4_extract_lane::<1>(h4_final);
+                },
+                2 => {
+                    results[base_idx] = u32x4_extract_lane::<2>(h0_final);
+                    results[base_idx + 1] = u32x4_extract_lane::<2>(h1_final);
+                    results[base_idx + 2] = u32x4_extract_lane::<2>(h2_final);
+                    results[base_idx + 3] = u32x4_extract_lane::<2>(h3_final);
+                    results[base_idx + 4] = u32x4_extract_lane::<2>(h4_final);
+                },
+                3 => {
+                    results[base_idx] = u32x4_extract_lane::<3>(h0_final);
+                    results[base_idx + 1] = u32x4_extract_lane::<3>(h1_final);
+                    results[base_idx + 2] = u32x4_extract_lane::<3>(h2_final);
+                    results[base_idx + 3] = u32x4_extract_lane::<3>(h3_final);
+                    results[base_idx + 4] = u32x4_extract_lane::<3>(h4_final);
+                },
+                _ => unreachable!(),
+            }
+        }
+    
+        results
+    }
+
+    /// SSE2版: 4組の16ワードメッセージを並列処理し、4組のハッシュ値を返す
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse2")]
+    fn sha1_x4_sse2(messages: &[u32; 64]) -> [u32; 20] {
+        // 80ワードのメッセージスケジュール配列（4組分並列）
+        let mut w = [_mm_setzero_si128(); 80];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = _mm_set_epi32(
+                messages[48 + i] as i32,
+                messages[32 + i] as i32,
+                messages[16 + i] as i32,
+                messages[i] as i32,
+            );
+        }
+        for i in 16..80 {
+            w[i] = sse2_schedule_word(&w, i);
+        }
+
+        let h_init = H_INIT.map(|h| _mm_set1_epi32(h as i32));
+        let state = sse2_compress(h_init, &w, 0);
+        sse2_finalize(h_init, state)
+    }
+
+    /// AVX2版: 8組の16ワードメッセージを並列処理し、8組のハッシュ値を返す
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    fn sha1_x8_avx2(messages: &[u32; 128]) -> [u32; 40] {
+        // 80ワードのメッセージスケジュール配列（8組分並列）
+        let mut w = [_mm256_setzero_si256(); 80];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = _mm256_set_epi32(
+                messages[112 + i] as i32,
+                messages[96 + i] as i32,
+                messages[80 + i] as i32,
+                messages[64 + i] as i32,
+                messages[48 + i] as i32,
+                messages[32 + i] as i32,
+                messages[16 + i] as i32,
+                messages[i] as i32,
+            );
+        }
+        for i in 16..80 {
+            w[i] = avx2_schedule_word(&w, i);
+        }
+
+        let h_init = H_INIT.map(|h| _mm256_set1_epi32(h as i32));
+        let state = avx2_compress(h_init, &w, 0);
+        avx2_finalize(h_init, state)
+    }
+}
@@ -0,0 +1,420 @@
+/// 探索ターゲット照合
+/// 探索ヒットの判定条件（32bitシード、64bit初期シードの完全一致・範囲・マスク付き一致）を保持する
+/// 数百万件のターゲットでも探索速度が落ちないよう、完全一致はソート済み配列と上位16bitのバケット索引で判定する
+use wasm_bindgen::prelude::*;
+use std::ops::BitAnd;
+
+/// 32bitシードの完全一致用ソート済み配列
+/// 大規模なリストでは上位16bitごとの開始位置を索引として持ち、二分探索の範囲を絞る
+#[derive(Debug, Clone, Default)]
+struct SortedSeeds32 {
+    seeds: Vec<u32>,
+    /// 上位16bitごとの開始位置（要素数65537）。小規模なリストでは空
+    bucket_offsets: Vec<u32>,
+}
+
+impl SortedSeeds32 {
+    /// バケット索引を構築する要素数の下限
+    const BUCKET_INDEX_THRESHOLD: usize = 4096;
+
+    fn extend(&mut self, seeds: &[u32]) {
+        if seeds.is_empty() {
+            return;
+        }
+        merge_sorted(&mut self.seeds, seeds);
+        self.rebuild_index();
+    }
+
+    fn rebuild_index(&mut self) {
+        self.bucket_offsets.clear();
+        if self.seeds.len() < Self::BUCKET_INDEX_THRESHOLD {
+            return;
+        }
+
+        self.bucket_offsets.reserve(0x10001);
+        let mut index = 0;
+        for bucket in 0..=0x10000u32 {
+            while index < self.seeds.len() && (self.seeds[index] >> 16) < bucket {
+                index += 1;
+            }
+            self.bucket_offsets.push(index as u32);
+        }
+    }
+
+    #[inline(always)]
+    fn contains(&self, seed: u32) -> bool {
+        if self.bucket_offsets.is_empty() {
+            return self.seeds.binary_search(&seed).is_ok();
+        }
+        let bucket = (seed >> 16) as usize;
+        let start = self.bucket_offsets[bucket] as usize;
+        let end = self.bucket_offsets[bucket + 1] as usize;
+        self.seeds[start..end].binary_search(&seed).is_ok()
+    }
+}
+
+/// 昇順・重複なしの配列に値を追加する
+/// 追加分だけを整列してから既存の配列と併合するため、既存の要素は並べ直さない
+fn merge_sorted<T: Ord + Copy>(sorted: &mut Vec<T>, values: &[T]) {
+    let mut added = values.to_vec();
+    added.sort_unstable();
+    added.dedup();
+
+    let existing = std::mem::take(sorted);
+    sorted.reserve(existing.len() + added.len());
+    let (mut i, mut j) = (0, 0);
+    while i < existing.len() && j < added.len() {
+        match existing[i].cmp(&added[j]) {
+            std::cmp::Ordering::Less => {
+                sorted.push(existing[i]);
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                sorted.push(added[j]);
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                sorted.push(existing[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    sorted.extend_from_slice(&existing[i..]);
+    sorted.extend_from_slice(&added[j..]);
+}
+
+/// 閉区間の集合（開始値順に整列し、重なる区間は統合する）
+#[derive(Debug, Clone)]
+struct ValueRanges<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for ValueRanges<T> {
+    fn default() -> Self {
+        ValueRanges { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> ValueRanges<T> {
+    /// 区間[start, end]を追加（start > endの場合は空区間として無視）
+    fn add(&mut self, start: T, end: T) {
+        self.extend(&[(start, end)]);
+    }
+
+    /// 区間をまとめて追加（start > endの区間は無視）
+    /// 整列と統合は呼び出しごとに1回だけ行うため、まとめて渡すほど効率がよい
+    fn extend(&mut self, ranges: &[(T, T)]) {
+        let before = self.ranges.len();
+        self.ranges.extend(ranges.iter().copied().filter(|&(start, end)| start <= end));
+        if self.ranges.len() == before {
+            return;
+        }
+        self.ranges.sort_unstable();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(self.ranges.len());
+        for &(start, end) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    #[inline(always)]
+    fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(start, _)| start <= value);
+        index > 0 && value <= self.ranges[index - 1].1
+    }
+}
+
+/// マスク付き一致条件の集合: `(value & mask) == expected`
+#[derive(Debug, Clone)]
+struct MaskedValues<T> {
+    patterns: Vec<(T, T)>,
+}
+
+impl<T> Default for MaskedValues<T> {
+    fn default() -> Self {
+        MaskedValues { patterns: Vec::new() }
+    }
+}
+
+impl<T: Copy + PartialEq + BitAnd<Output = T>> MaskedValues<T> {
+    fn add(&mut self, value: T, mask: T) {
+        self.patterns.push((value & mask, mask));
+    }
+
+    #[inline(always)]
+    fn contains(&self, value: T) -> bool {
+        self.patterns.iter().any(|&(expected, mask)| value & mask == expected)
+    }
+}
+
+/// 探索ターゲット
+/// 32bitシードは初期シードをLCGで1回進めた値の上位32bit、64bit初期シードはSHA-1から得た進める前の値。
+/// 追加した条件のいずれかに一致すればヒットとなる
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct TargetMatcher {
+    seeds32: SortedSeeds32,
+    ranges32: ValueRanges<u32>,
+    masks32: MaskedValues<u32>,
+    seeds64: Vec<u64>,
+    ranges64: ValueRanges<u64>,
+    masks64: MaskedValues<u64>,
+}
+
+#[wasm_bindgen]
+impl TargetMatcher {
+    /// 条件を持たない照合器を作成（何にも一致しない）
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TargetMatcher {
+        TargetMatcher::default()
+    }
+
+    /// 32bitシードの集合から作成
+    ///
+    /// # Arguments
+    /// * `seeds` - 対象の32bitシード
+    pub fn from_seeds(seeds: &[u32]) -> TargetMatcher {
+        let mut matcher = TargetMatcher::new();
+        matcher.add_seeds(seeds);
+        matcher
+    }
+
+    /// 64bit初期シードの集合から作成
+    ///
+    /// # Arguments
+    /// * `seeds` - 対象の64bit初期シード
+    pub fn from_seeds_u64(seeds: &[u64]) -> TargetMatcher {
+        let mut matcher = TargetMatcher::new();
+        matcher.add_seeds_u64(seeds);
+        matcher
+    }
+
+    /// 32bitシードのマスク付き一致: `(seed & mask) == (value & mask)`
+    ///
+    /// # Arguments
+    /// * `value` - 比較する値
+    /// * `mask` - 比較するビット
+    pub fn masked(value: u32, mask: u32) -> TargetMatcher {
+        let mut matcher = TargetMatcher::new();
+        matcher.add_mask(value, mask);
+        matcher
+    }
+
+    /// 64bit初期シードのマスク付き一致: `(initial_seed & mask) == (value & mask)`
+    ///
+    /// # Arguments
+    /// * `value` - 比較する値
+    /// * `mask` - 比較するビット
+    pub fn masked_u64(value: u64, mask: u64) -> TargetMatcher {
+        let mut matcher = TargetMatcher::new();
+        matcher.add_mask_u64(value, mask);
+        matcher
+    }
+
+    /// 32bitシードを追加（重複は除去される）
+    /// 追加分のみ整列して既存の配列と併合するため、まとめて渡すほど効率がよい
+    pub fn add_seeds(&mut self, seeds: &[u32]) {
+        self.seeds32.extend(seeds);
+    }
+
+    /// 64bit初期シードを追加（重複は除去される）
+    pub fn add_seeds_u64(&mut self, seeds: &[u64]) {
+        merge_sorted(&mut self.seeds64, seeds);
+    }
+
+    /// 32bitシードの閉区間[start, end]を追加（start > endの場合は何もしない）
+    pub fn add_range(&mut self, start: u32, end: u32) {
+        self.ranges32.add(start, end);
+    }
+
+    /// 64bit初期シードの閉区間[start, end]を追加（start > endの場合は何もしない）
+    pub fn add_range_u64(&mut self, start: u64, end: u64) {
+        self.ranges64.add(start, end);
+    }
+
+    /// 32bitシードのマスク付き一致条件を追加
+    pub fn add_mask(&mut self, value: u32, mask: u32) {
+        self.masks32.add(value, mask);
+    }
+
+    /// 64bit初期シードのマスク付き一致条件を追加
+    pub fn add_mask_u64(&mut self, value: u64, mask: u64) {
+        self.masks64.add(value, mask);
+    }
+
+    /// 完全一致で登録されたシードの件数（32bitと64bitの合計）
+    #[wasm_bindgen(getter)]
+    pub fn seed_count(&self) -> u32 {
+        (self.seeds32.seeds.len() + self.seeds64.len()) as u32
+    }
+
+    /// 条件が1つも登録されていないか
+    #[wasm_bindgen(getter)]
+    pub fn is_empty(&self) -> bool {
+        self.seeds32.seeds.is_empty()
+            && self.ranges32.ranges.is_empty()
+            && self.masks32.patterns.is_empty()
+            && self.seeds64.is_empty()
+            && self.ranges64.ranges.is_empty()
+            && self.masks64.patterns.is_empty()
+    }
+}
+
+impl TargetMatcher {
+    /// 32bitシードの閉区間をまとめて追加（start > endの区間は無視）
+    /// 整列と統合は1回だけ行うため、区間が多い場合は`add_range`の繰り返しより効率がよい
+    pub fn add_ranges(&mut self, ranges: &[(u32, u32)]) {
+        self.ranges32.extend(ranges);
+    }
+
+    /// 64bit初期シードの閉区間をまとめて追加（start > endの区間は無視）
+    pub fn add_ranges_u64(&mut self, ranges: &[(u64, u64)]) {
+        self.ranges64.extend(ranges);
+    }
+
+    /// 32bitシードと64bit初期シードがいずれかの条件に一致するか判定
+    #[inline(always)]
+    pub fn matches(&self, seed: u32, lcg_seed: u64) -> bool {
+        self.seeds32.contains(seed)
+            || self.ranges32.contains(seed)
+            || self.masks32.contains(seed)
+            || self.seeds64.binary_search(&lcg_seed).is_ok()
+            || self.ranges64.contains(lcg_seed)
+            || self.masks64.contains(lcg_seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_matching() {
+        let seeds32 = TargetMatcher::from_seeds(&[0x9ABCDEF0, 0x12345678, 0x12345678]);
+        assert_eq!(seeds32.seed_count(), 2);
+        assert!(seeds32.matches(0x12345678, 0));
+        assert!(!seeds32.matches(0x12345679, 0x12345678));
+
+        let seeds64 = TargetMatcher::from_seeds_u64(&[0x0123456789ABCDEF]);
+        assert!(seeds64.matches(0, 0x0123456789ABCDEF));
+        assert!(!seeds64.matches(0x89ABCDEF, 0x0123456789ABCDEE));
+
+        assert!(TargetMatcher::new().is_empty());
+        assert!(!TargetMatcher::new().matches(0, 0));
+    }
+
+    #[test]
+    fn test_masked_matching() {
+        let upper16 = TargetMatcher::masked(0x1234FFFF, 0xFFFF0000);
+        assert!(upper16.matches(0x12340000, 0));
+        assert!(upper16.matches(0x1234ABCD, 0));
+        assert!(!upper16.matches(0x1235ABCD, 0));
+
+        let lower32 = TargetMatcher::masked_u64(0xFFFF_FFFF_0000_BEEF, 0x0000_0000_FFFF_FFFF);
+        assert!(lower32.matches(0, 0x1234_5678_0000_BEEF));
+        assert!(!lower32.matches(0, 0x1234_5678_0001_BEEF));
+    }
+
+    #[test]
+    fn test_range_matching() {
+        let mut matcher = TargetMatcher::new();
+        matcher.add_range(0x1000, 0x1FFF);
+        matcher.add_range(0x1800, 0x27FF);
+        matcher.add_range(0xFFFF_FF00, 0xFFFF_FFFF);
+        matcher.add_range(0x9000, 0x8000); // 空区間
+        assert_eq!(matcher.ranges32.ranges, vec![(0x1000, 0x27FF), (0xFFFF_FF00, 0xFFFF_FFFF)]);
+
+        assert!(matcher.matches(0x1000, 0));
+        assert!(matcher.matches(0x27FF, 0));
+        assert!(matcher.matches(0xFFFF_FFFF, 0));
+        assert!(!matcher.matches(0x0FFF, 0));
+        assert!(!matcher.matches(0x2800, 0));
+        assert!(!matcher.matches(0x8800, 0));
+
+        matcher.add_range_u64(u64::MAX - 1, u64::MAX);
+        assert!(matcher.matches(0, u64::MAX));
+        assert!(!matcher.matches(0, u64::MAX - 2));
+    }
+
+    #[test]
+    fn test_bulk_ranges_match_incremental() {
+        let ranges = [(0x1800, 0x27FF), (0x9000, 0x8000), (0x1000, 0x1FFF), (0x2800, 0x2FFF), (0x5000, 0x5000)];
+        let mut incremental = TargetMatcher::new();
+        for &(start, end) in &ranges {
+            incremental.add_range(start, end);
+        }
+        let mut bulk = TargetMatcher::new();
+        bulk.add_ranges(&ranges);
+        assert_eq!(bulk.ranges32.ranges, incremental.ranges32.ranges);
+        assert_eq!(bulk.ranges32.ranges, vec![(0x1000, 0x27FF), (0x2800, 0x2FFF), (0x5000, 0x5000)]);
+
+        bulk.add_ranges_u64(&[(10, 20), (15, 30), (5, 1)]);
+        assert_eq!(bulk.ranges64.ranges, vec![(10, 30)]);
+    }
+
+    #[test]
+    fn test_combined_conditions() {
+        let mut matcher = TargetMatcher::from_seeds(&[42]);
+        matcher.add_range(100, 200);
+        matcher.add_mask_u64(0xAB, 0xFF);
+        assert!(matcher.matches(42, 0));
+        assert!(matcher.matches(150, 0));
+        assert!(matcher.matches(7, 0x1234_00AB));
+        assert!(!matcher.matches(7, 0x1234_00AC));
+    }
+
+    #[test]
+    fn test_incremental_adds_match_single_add() {
+        let mut incremental = TargetMatcher::new();
+        incremental.add_seeds(&[50, 10, 30]);
+        incremental.add_seeds(&[]);
+        incremental.add_seeds(&[30, 5, 60, 10, 40]);
+        incremental.add_seeds(&[u32::MAX, 0]);
+        assert_eq!(incremental.seeds32.seeds, vec![0, 5, 10, 30, 40, 50, 60, u32::MAX]);
+
+        incremental.add_seeds_u64(&[7, 3]);
+        incremental.add_seeds_u64(&[3, 1, 9]);
+        assert_eq!(incremental.seeds64, vec![1, 3, 7, 9]);
+
+        // 閾値をまたいで追加してもバケット索引が再構築される
+        let seeds: Vec<u32> = (0..5000u32).map(|i| i.wrapping_mul(0x9E3779B9)).collect();
+        let mut chunked = TargetMatcher::new();
+        for chunk in seeds.chunks(1000) {
+            chunked.add_seeds(chunk);
+        }
+        let whole = TargetMatcher::from_seeds(&seeds);
+        assert_eq!(chunked.seeds32.seeds, whole.seeds32.seeds);
+        assert_eq!(chunked.seeds32.bucket_offsets, whole.seeds32.bucket_offsets);
+        assert!(seeds.iter().all(|&seed| chunked.matches(seed, 0)));
+    }
+
+    #[test]
+    fn test_large_seed_list_uses_bucket_index() {
+        // 線形合同法で擬似乱数的な100万件のシードを生成
+        let mut value = 0x12345678u32;
+        let seeds: Vec<u32> = (0..1_000_000)
+            .map(|_| {
+                value = value.wrapping_mul(0x41C64E6D).wrapping_add(0x6073);
+                value
+            })
+            .collect();
+        let matcher = TargetMatcher::from_seeds(&seeds);
+        assert_eq!(matcher.seeds32.bucket_offsets.len(), 0x10001);
+
+        for &seed in seeds.iter().step_by(9973) {
+            assert!(matcher.matches(seed, 0));
+        }
+        // 索引付き判定と全体の二分探索が一致すること
+        let mut probe = 0xDEADBEEFu32;
+        for _ in 0..100_000 {
+            probe = probe.wrapping_mul(0x6C078965).wrapping_add(1);
+            assert_eq!(matcher.matches(probe, 0), matcher.seeds32.seeds.binary_search(&probe).is_ok());
+        }
+        assert!(matcher.matches(0xFFFF_FFFF, 0) == seeds.contains(&0xFFFF_FFFF));
+    }
+}
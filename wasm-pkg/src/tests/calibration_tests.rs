@@ -0,0 +1,55 @@
+/// 本体キャリブレーションのテストコード
+use crate::calibration::ConsoleCalibration;
+use crate::integrated_search::IntegratedSeedSearcher;
+use crate::likelihood::Timer0VCountLikelihood;
+use crate::multi_profile_search::MultiProfileSearcher;
+use crate::target_matcher::TargetMatcher;
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+#[test]
+fn test_calibration_solves_timer0_vcount_from_hits() {
+    // 2012-06-15 12:00:XX に起動したときのシード
+    let seed_at = |key_input, second, timer0| {
+        IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", key_input, 8).unwrap()
+            .list_seeds(2012, 6, 15, 12, 0, second, 1, timer0, timer0, 0x60, 0x60).unwrap()[0]
+    };
+
+    // 探索器のkey_inputとは異なるキー入力で起動した観測も解ける
+    let searcher = default_searcher();
+    let mut calibration = ConsoleCalibration::new();
+    for (offset, timer0, key_input) in [(7, 0xC7A, 0x2FFE), (20, 0xC7A, 0x2FFF), (33, 0xC79, 0x2FFF)] {
+        let seed = seed_at(key_input, offset, timer0);
+        let explained = calibration.try_add_hit(
+            &searcher, 2012, 6, 15, 12, 0, offset, key_input, &TargetMatcher::from_seeds(&[seed]),
+            0xC70, 0xC80, 0x5F, 0x61,
+        ).unwrap();
+        assert_eq!(explained, 1);
+    }
+
+    // 一致しない観測は記録されない
+    let unexplained = calibration.try_add_hit(
+        &searcher, 2012, 6, 15, 12, 0, 0, 0x2FFF, &TargetMatcher::from_seeds(&[seed_at(0x2FFF, 1, 0xC7A)]),
+        0xC70, 0xC80, 0x5F, 0x61,
+    ).unwrap();
+    assert_eq!(unexplained, 0);
+
+    assert_eq!(calibration.hit_count(), 3);
+    assert_eq!(calibration.observations(), vec![0xC79, 0x60, 1, 0xC7A, 0x60, 2]);
+    assert_eq!((calibration.timer0_min(), calibration.timer0_max()), (Some(0xC79), Some(0xC7A)));
+    assert_eq!((calibration.vcount_min(), calibration.vcount_max()), (Some(0x60), Some(0x60)));
+
+    // 保存・復元した結果から尤度モデルを作り、順位付けに使う
+    let restored = ConsoleCalibration::parse(&calibration.serialize()).unwrap();
+    let likelihood = restored.likelihood(&Timer0VCountLikelihood::new(0xC79, 0xC7A, 0x60, 0x60));
+    assert!(likelihood.probability(0xC7A, 0x60) > likelihood.probability(0xC79, 0x60));
+
+    // 絞り込んだ範囲を探索器に適用すると、観測範囲内の組だけが探索され、多く観測された組が先に並ぶ
+    assert_eq!(restored.narrowed_vcount_timer0_ranges(), vec![0x60, 0xC79, 0xC7A]);
+    let inside = [seed_at(0x2FFF, 50, 0xC79), seed_at(0x2FFF, 51, 0xC7A)];
+    let outside = seed_at(0x2FFF, 52, 0xC7B);
+    let mut multi = MultiProfileSearcher::new();
+    assert_eq!(multi.add_calibrated_profiles(&searcher, &restored).unwrap(), 1);
+    let mut results = multi.search_seeds(2012, 6, 15, 12, 0, 50, 3, &[inside[0], inside[1], outside]).unwrap();
+    multi.rank_results(&mut results);
+    assert_eq!(results.iter().map(|r| (r.seed(), r.timer0())).collect::<Vec<_>>(), vec![(inside[1], 0xC7A), (inside[0], 0xC79)]);
+}
@@ -0,0 +1,10 @@
+/// 探索テスト共通のフィクスチャ
+use crate::integrated_search::IntegratedSeedSearcher;
+
+pub(crate) const TEST_MAC: [u8; 6] = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+pub(crate) const TEST_NAZO: [u32; 5] = [0x02215f10, 0x01000000, 0xc0000000, 0x00007fff, 0x00000000];
+
+/// 既定パラメータ（DS・キー入力なし・フレーム8）の探索器
+pub(crate) fn default_searcher() -> IntegratedSeedSearcher {
+    IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8).unwrap()
+}
@@ -1,5 +1,4 @@
 /// オフセット計算と個体生成を統合したエンドツーエンドテスト
-
 use crate::pokemon_generator::{PokemonGenerator, BWGenerationConfig};
 use crate::offset_calculator::{GameMode, calculate_game_offset};
 use crate::encounter_calculator::{GameVersion, EncounterType};
@@ -414,7 +413,7 @@ fn test_integrated_generation_pattern4_bw2_continue_no_memory_link_static_starte
 /// 複数パターンの統合テスト（バッチ処理）
 #[test]
 fn test_integrated_generation_multiple_patterns() {
-    let test_cases = vec![
+    let test_cases = [
         // (初期シード, ゲームモード, エンカウントタイプ, 期待生成シード)
         (0x11111u64, GameMode::Bw2ContinueNoMemoryLink, EncounterType::Normal, 0x181A996368932CFCu64),
         (0x77777u64, GameMode::BwContinue, EncounterType::Surfing, 0x30CB71FDDDA5E880u64),
@@ -153,8 +153,8 @@ mod wasm_tests {
             let time_index = i % 86400;
             let date_index = i % 36525;
             
-            let date_code = DateCodeGenerator::get_date_code(date_index);
-            let time_code = TimeCodeGenerator::get_time_code(time_index);
+            let date_code = DateCodeGenerator::get_date_code(date_index).unwrap();
+            let time_code = TimeCodeGenerator::get_time_code(time_index).unwrap();
             
             total_codes = total_codes.wrapping_add(date_code as u64 + time_code as u64);
         }
@@ -263,8 +263,8 @@ mod wasm_tests {
             let date_index = (current_seconds_since_2000 / 86400) as u32;
             
             // 日時コード取得
-            let time_code = TimeCodeGenerator::get_time_code_for_hardware(time_index, hardware);
-            let date_code = DateCodeGenerator::get_date_code(date_index);
+            let time_code = TimeCodeGenerator::get_time_code_for_hardware(time_index, hardware).unwrap();
+            let date_code = DateCodeGenerator::get_date_code(date_index).unwrap();
             
             // Timer0とVCount範囲探索
             for timer0 in 0..timer0_range {
@@ -363,7 +363,684 @@ mod native_tests {
         assert_eq!(result.vcount(), 50);
     }
 
-    // 注意: IntegratedSeedSearcherはwasm_bindgen依存のため、
-    // ネイティブ環境ではテストできません。
-    // WASMテストでのみ実行されます。
+    // ==== IntegratedSeedSearcher のテスト（ネイティブAPI） ====
+
+    use crate::error::InitSeedError;
+    use crate::integrated_search::IntegratedSeedSearcher;
+    use crate::sha1::{calculate_pokemon_sha1, calculate_pokemon_seed_from_hash, swap_bytes_32};
+    use crate::datetime_codes::{TimeCodeGenerator, DateCodeGenerator};
+    use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+    /// 探索器を使わずにメッセージを直接組み立ててシードを計算する（DS, key_input=0x2FFF, frame=8）
+    fn reference_seed(seconds_since_2000: u32, timer0: u32, vcount: u32) -> u32 {
+        reference_seed_with_key(seconds_since_2000, timer0, vcount, 0x2FFF)
+    }
+
+    /// キー入力を指定してシードを計算する（DS, frame=8）
+    fn reference_seed_with_key(seconds_since_2000: u32, timer0: u32, vcount: u32, key_input: u32) -> u32 {
+        reference_seed_with_weekday(seconds_since_2000, timer0, vcount, key_input, None)
+    }
+
+    /// RTCの曜日（None は暦どおり）を指定してシードを計算する（DS, frame=8）
+    fn reference_seed_with_weekday(seconds_since_2000: u32, timer0: u32, vcount: u32, key_input: u32, weekday: Option<u32>) -> u32 {
+        let mut message = [0u32; 16];
+        for i in 0..5 {
+            message[i] = swap_bytes_32(TEST_NAZO[i]);
+        }
+        message[5] = swap_bytes_32((vcount << 16) | timer0);
+        message[6] = ((TEST_MAC[4] as u32) << 8) | (TEST_MAC[5] as u32);
+        let mac_upper = u32::from_le_bytes([TEST_MAC[0], TEST_MAC[1], TEST_MAC[2], TEST_MAC[3]]);
+        message[7] = swap_bytes_32(mac_upper ^ 0x06000000 ^ 8);
+        message[8] = DateCodeGenerator::get_date_code(seconds_since_2000 / 86400).unwrap();
+        if let Some(weekday) = weekday {
+            message[8] = (message[8] & !0xFF) | weekday;
+        }
+        message[9] = TimeCodeGenerator::get_time_code_for_hardware(seconds_since_2000 % 86400, "DS").unwrap();
+        message[12] = swap_bytes_32(key_input);
+        message[13] = 0x80000000;
+        message[15] = 0x000001A0;
+        let (h0, h1, _, _, _) = calculate_pokemon_sha1(&message);
+        calculate_pokemon_seed_from_hash(h0, h1)
+    }
+
+    /// 2012-06-15 12:00:00 の2000年からの経過秒
+    const BASE_SECONDS: u32 = 1339761600 - 946684800;
+
+    #[test]
+    fn test_integrated_searcher_creation() {
+        assert!(IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8).is_ok());
+        assert!(IntegratedSeedSearcher::try_new(&TEST_MAC[..5], &TEST_NAZO, "DS", 0x2FFF, 8).is_err());
+        assert!(IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO[..4], "DS", 0x2FFF, 8).is_err());
+        assert!(IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "INVALID", 0x2FFF, 8).is_err());
+    }
+
+    #[test]
+    fn test_native_search_finds_reference_seed() {
+        let searcher = default_searcher();
+
+        // 探索範囲内の 12:00:37, Timer0=0xC7A, VCount=0x60 をターゲットにする
+        let target = reference_seed(BASE_SECONDS + 37, 0xC7A, 0x60);
+        let results = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 50, 0xC79, 0xC7A, 0x60, 0x60, &[target]).unwrap();
+
+        let hit = results
+            .iter()
+            .find(|r| r.timer0() == 0xC7A && r.second() == 37)
+            .expect("reference seed should be found");
+        assert_eq!(hit.seed(), target);
+        assert_eq!((hit.year(), hit.month(), hit.date(), hit.hour(), hit.minute()), (2012, 6, 15, 12, 0));
+        assert_eq!(hit.vcount(), 0x60);
+        assert_eq!(hit.hash().len(), 40);
+    }
+
+    #[test]
+    fn test_native_scalar_and_simd_results_match() {
+        let searcher = default_searcher();
+
+        // 8の倍数でない秒数で端数処理も含めて比較する
+        let targets: Vec<u32> = (0..23)
+            .step_by(3)
+            .map(|offset| reference_seed(BASE_SECONDS + offset, 0xC79 + offset % 2, 0x60))
+            .collect();
+        let scalar = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 23, 0xC79, 0xC7A, 0x5F, 0x60, &targets).unwrap();
+        let simd = searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 23, 0xC79, 0xC7A, 0x5F, 0x60, &targets).unwrap();
+
+        assert!(scalar.len() >= targets.len());
+        assert_eq!(scalar.len(), simd.len());
+        for (a, b) in scalar.iter().zip(simd.iter()) {
+            assert_eq!(
+                (a.seed(), a.hash(), a.second(), a.timer0(), a.vcount()),
+                (b.seed(), b.hash(), b.second(), b.timer0(), b.vcount())
+            );
+        }
+    }
+
+    #[test]
+    fn test_parallel_search_matches_single_thread_order() {
+        use crate::integrated_search::SearchRange;
+
+        let searcher = default_searcher();
+        let targets: Vec<u32> = (0..61)
+            .step_by(5)
+            .map(|offset| reference_seed(BASE_SECONDS + offset, 0xC79 + offset % 3, 0x5F + offset % 2))
+            .collect();
+        let expected = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 61, 0xC79, 0xC7B, 0x5F, 0x60, &targets).unwrap();
+        assert!(expected.len() >= targets.len());
+
+        // 小さいチャンク幅で秒方向の分割とスレッド間の順序復元を検証する
+        let range = SearchRange {
+            base_seconds_since_2000: BASE_SECONDS as i64,
+            range_seconds: 61,
+            timer0_min: 0xC79,
+            timer0_max: 0xC7B,
+            vcount_min: 0x5F,
+            vcount_max: 0x60,
+        };
+        for thread_count in [1, 3, 0] {
+            let parallel = searcher.search_range_parallel(&range, &crate::target_matcher::TargetMatcher::from_seeds(&targets), thread_count, 8);
+            let key = |r: &crate::integrated_search::SearchResult| (r.seed(), r.second(), r.timer0(), r.vcount());
+            assert_eq!(
+                parallel.iter().map(key).collect::<Vec<_>>(),
+                expected.iter().map(key).collect::<Vec<_>>(),
+                "thread_count={thread_count}"
+            );
+        }
+
+        let public = searcher.search_seeds_parallel(2012, 6, 15, 12, 0, 0, 61, 0xC79, 0xC7B, 0x5F, 0x60, &targets, 0).unwrap();
+        assert_eq!(public.len(), expected.len());
+    }
+
+    #[test]
+    fn test_parallel_search_orders_multiple_keys_across_chunks() {
+        use crate::integrated_search::SearchRange;
+
+        // キー入力4種 × 複数チャンク（日付境界もまたぐ）で単一スレッド版と順序が一致すること
+        let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x3, 8).unwrap();
+        assert_eq!(searcher.key_inputs().len(), 4);
+        let start = BASE_SECONDS + 12 * 3600 - 20;
+        let targets: Vec<u32> = searcher
+            .key_inputs()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &key_input)| {
+                [1u32, 18, 27, 39].map(|offset| reference_seed_with_key(start + offset + i as u32, 0xC79 + offset % 2, 0x60, key_input))
+            })
+            .collect();
+        let expected = searcher.search_seeds(2012, 6, 15, 23, 59, 40, 45, 0xC79, 0xC7A, 0x60, 0x60, &targets).unwrap();
+        assert!(expected.len() >= targets.len());
+
+        let range = SearchRange {
+            base_seconds_since_2000: start as i64,
+            range_seconds: 45,
+            timer0_min: 0xC79,
+            timer0_max: 0xC7A,
+            vcount_min: 0x60,
+            vcount_max: 0x60,
+        };
+        let summary = |r: &crate::integrated_search::SearchResult| (r.seed(), r.date(), r.second(), r.timer0(), r.vcount(), r.key_input());
+        for thread_count in [1, 3, 0] {
+            let parallel = searcher.search_range_parallel(&range, &crate::target_matcher::TargetMatcher::from_seeds(&targets), thread_count, 8);
+            assert_eq!(
+                parallel.iter().map(summary).collect::<Vec<_>>(),
+                expected.iter().map(summary).collect::<Vec<_>>(),
+                "thread_count={thread_count}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_search_invalid_start_date() {
+        let searcher = default_searcher();
+        let error = searcher.search_seeds(2012, 2, 30, 0, 0, 0, 10, 0xC79, 0xC79, 0x60, 0x60, &[0]).unwrap_err();
+        assert_eq!(error, InitSeedError::InvalidDate { year: 2012, month: 2, day: 30, hour: 0, minute: 0, second: 0 });
+        assert_eq!(error.code(), "INVALID_DATE");
+    }
+
+    #[test]
+    fn test_native_search_rejects_invalid_inputs() {
+        let searcher = default_searcher();
+
+        // 2099-12-31 23:59:59を越える範囲
+        assert_eq!(
+            searcher.search_seeds(2099, 12, 31, 23, 59, 50, 11, 0xC79, 0xC79, 0x60, 0x60, &[0]).unwrap_err(),
+            InitSeedError::DateOutOfRange
+        );
+        assert!(searcher.search_seeds(2099, 12, 31, 23, 59, 50, 10, 0xC79, 0xC79, 0x60, 0x60, &[0]).is_ok());
+
+        // Timer0・VCount範囲の逆転や16bit超過
+        assert_eq!(
+            searcher.search_seeds(2012, 6, 15, 12, 0, 0, 10, 0xC7A, 0xC79, 0x60, 0x60, &[0]).unwrap_err(),
+            InitSeedError::InvalidTimer0Range { min: 0xC7A, max: 0xC79 }
+        );
+        assert_eq!(
+            searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 10, 0xC79, 0x10000, 0x60, 0x60, &[0]).unwrap_err(),
+            InitSeedError::InvalidTimer0Range { min: 0xC79, max: 0x10000 }
+        );
+        assert_eq!(
+            searcher.list_seeds(2012, 6, 15, 12, 0, 0, 10, 0xC79, 0xC79, 0x61, 0x60).unwrap_err(),
+            InitSeedError::InvalidVCountRange { min: 0x61, max: 0x60 }
+        );
+
+        // 構築時の検証
+        assert_eq!(
+            IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "GBA", 0x2FFF, 8).err(),
+            Some(InitSeedError::UnknownHardware("GBA".to_string()))
+        );
+        assert_eq!(
+            IntegratedSeedSearcher::try_new(&TEST_MAC[..5], &TEST_NAZO, "DS", 0x2FFF, 8).err(),
+            Some(InitSeedError::InvalidMacLength(5))
+        );
+        assert_eq!(
+            IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1000, 8).err(),
+            Some(InitSeedError::InvalidKeyMask(0x1000))
+        );
+    }
+
+    #[test]
+    fn test_search_across_midnight_matches_reference() {
+        let searcher = default_searcher();
+
+        // 2012-06-15 23:59:50 から20秒間（日付境界をまたぐ）の全秒をターゲットにする
+        let start = BASE_SECONDS + 12 * 3600 - 10;
+        let targets: Vec<u32> = (0..20).map(|offset| reference_seed(start + offset, 0xC79, 0x60)).collect();
+
+        let scalar = searcher.search_seeds(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap();
+        let simd = searcher.search_seeds_simd(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap();
+
+        for results in [&scalar, &simd] {
+            let seeds: Vec<u32> = results.iter().map(|r| r.seed()).collect();
+            assert_eq!(seeds, targets);
+            assert_eq!((results[9].date(), results[9].hour(), results[9].second()), (15, 23, 59));
+            assert_eq!((results[10].date(), results[10].hour(), results[10].second()), (16, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_key_enumeration_search_reports_key_input() {
+        use crate::key_input::{KEY_A, KEY_START, KEY_UP, KEY_DOWN};
+
+        let allowed = KEY_A | KEY_START | KEY_UP | KEY_DOWN;
+        let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", allowed, 8).unwrap();
+        // 2 × 2 × 3（上下同時押しを除外）
+        assert_eq!(searcher.key_inputs().len(), 12);
+        assert!(!searcher.key_inputs().contains(&(0x2FFF ^ (KEY_UP | KEY_DOWN))));
+
+        let key_input = 0x2FFF ^ (KEY_A | KEY_UP);
+        let target = reference_seed_with_key(BASE_SECONDS + 5, 0xC79, 0x60, key_input);
+        let scalar = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 10, 0xC79, 0xC79, 0x60, 0x60, &[target]).unwrap();
+        let simd = searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 10, 0xC79, 0xC79, 0x60, 0x60, &[target]).unwrap();
+
+        for results in [&scalar, &simd] {
+            let hit = results.iter().find(|r| r.second() == 5).expect("hit for enumerated key input");
+            assert_eq!(hit.key_input(), key_input);
+        }
+
+        // ボタン以外のビットはエラー
+        assert!(IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1000, 8).is_err());
+    }
+
+    #[test]
+    fn test_rom_parameters_fill_nazo_and_default_ranges() {
+        use crate::hardware::{Hardware, HardwareProfile};
+        use crate::multi_profile_search::MultiProfileSearcher;
+        use crate::rom_parameters::{RomParameters, RomRegion, RomVersion};
+
+        let ds = HardwareProfile::new(Hardware::Ds);
+        let b_jpn = RomParameters::for_hardware(RomVersion::Black, RomRegion::Jpn, &ds).unwrap();
+        let from_rom = IntegratedSeedSearcher::try_from_rom(&TEST_MAC, RomVersion::Black, RomRegion::Jpn, &ds, 0x2FFF).unwrap();
+        let by_hand = IntegratedSeedSearcher::try_new(&TEST_MAC, b_jpn.nazo_words(), "DS", 0x2FFF, 8).unwrap();
+        assert_eq!(from_rom.config_fingerprint(), by_hand.config_fingerprint());
+        assert_eq!(from_rom.default_ranges(), b_jpn.ranges());
+        assert_eq!(from_rom.default_vcount_timer0_ranges(), vec![0x60, 0xC79, 0xC7A]);
+        assert!(by_hand.default_ranges().is_empty());
+
+        // BWのDSi・3DS向けnazo値はDS系で代用しない
+        let dsi = HardwareProfile::new(Hardware::Dsi);
+        assert_eq!(
+            IntegratedSeedSearcher::try_from_rom(&TEST_MAC, RomVersion::Black, RomRegion::Jpn, &dsi, 0x2FFF).err().map(|e| e.code()),
+            Some("UNSUPPORTED_ROM_HARDWARE")
+        );
+        // DSiはフレーム値の既定値がないため明示的に指定する
+        assert_eq!(
+            IntegratedSeedSearcher::try_from_rom(&TEST_MAC, RomVersion::Black2, RomRegion::Ger, &dsi, 0x2FFF).err(),
+            Some(InitSeedError::MissingFrame("DSI".to_string()))
+        );
+        let b2_on_dsi = IntegratedSeedSearcher::try_from_rom(&TEST_MAC, RomVersion::Black2, RomRegion::Ger, &dsi.with_frame(8), 0x2FFF).unwrap();
+        assert_eq!(b2_on_dsi.default_vcount_timer0_ranges(), vec![0x81, 0x10E5, 0x10E8, 0x82, 0x10E9, 0x10EC]);
+
+        // B2 GERはVCountごとにTimer0範囲が異なるため、範囲ごとにプロファイルが追加される
+        let searcher = IntegratedSeedSearcher::try_from_rom(&TEST_MAC, RomVersion::Black2, RomRegion::Ger, &ds, 0x2FFF).unwrap();
+        let seed_at = |timer0, vcount| searcher.list_seeds(2012, 6, 15, 12, 0, 0, 1, timer0, timer0, vcount, vcount).unwrap()[0];
+        let in_first = seed_at(0x10E6, 0x81);
+        let in_second = seed_at(0x10EA, 0x82);
+        let outside = seed_at(0x10E6, 0x82);
+
+        let mut multi = MultiProfileSearcher::new();
+        assert_eq!(multi.add_rom_profiles(&TEST_MAC, RomVersion::Black2, RomRegion::Ger, &ds, 0x2FFF).unwrap(), 2);
+        let results = multi.search_seeds(2012, 6, 15, 12, 0, 0, 1, &[in_first, in_second, outside]).unwrap();
+        let hits: Vec<_> = results.iter().map(|r| (r.seed(), r.profile_index(), r.timer0(), r.vcount())).collect();
+        assert_eq!(hits, vec![(in_first, 0, 0x10E6, 0x81), (in_second, 1, 0x10EA, 0x82)]);
+    }
+
+    #[test]
+    fn test_results_ranked_by_timer0_vcount_likelihood() {
+        use crate::likelihood::Timer0VCountLikelihood;
+        use crate::multi_profile_search::{MultiProfileSearcher, SearchProfile};
+
+        let searcher = default_searcher();
+        let early = reference_seed(BASE_SECONDS + 1, 0xC79, 0x60);
+        let late = reference_seed(BASE_SECONDS + 2, 0xC7A, 0x60);
+
+        // 観測済みのTimer0が先頭に来る
+        let mut model = Timer0VCountLikelihood::new(0xC79, 0xC7A, 0x60, 0x60);
+        model.observe(0xC7A, 0x60);
+        let mut results = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 4, 0xC79, 0xC7A, 0x60, 0x60, &[early, late]).unwrap();
+        assert_eq!(results.iter().map(|r| r.seed()).collect::<Vec<_>>(), vec![early, late]);
+        model.rank_results(&mut results);
+        assert_eq!(results.iter().map(|r| r.seed()).collect::<Vec<_>>(), vec![late, early]);
+
+        // 複数プロファイルではヒットしたプロファイルのモデルで順位付けする
+        let mut multi = MultiProfileSearcher::new();
+        multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8, 0xC79, 0xC79, 0x60, 0x60).unwrap());
+        multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8, 0xC7A, 0xC7A, 0x60, 0x60).unwrap());
+        let mut results = multi.search_seeds(2012, 6, 15, 12, 0, 0, 4, &[early, late]).unwrap();
+        multi.rank_results(&mut results);
+        assert_eq!(results.iter().map(|r| r.seed()).collect::<Vec<_>>(), vec![early, late], "uniform profiles keep search order");
+
+        let mut unlikely = Timer0VCountLikelihood::new(0xC79, 0xC7A, 0x60, 0x60);
+        unlikely.observe(0xC7A, 0x60);
+        multi.set_profile_likelihood(0, &unlikely).unwrap();
+        multi.rank_results(&mut results);
+        assert_eq!(results.iter().map(|r| (r.seed(), r.profile_index())).collect::<Vec<_>>(), vec![(late, 1), (early, 0)]);
+    }
+
+    #[test]
+    fn test_weekday_override_and_offset() {
+        use crate::datetime_codes::WeekdayRule;
+        use crate::job_spec::SearchJob;
+
+        // 2012-06-15は金曜日（5）、翌日は土曜日（6）。RTCは火曜日（2）を報告している
+        let first = reference_seed_with_weekday(BASE_SECONDS + 37, 0xC7A, 0x60, 0x2FFF, Some(2));
+        let second = reference_seed_with_weekday(BASE_SECONDS + 43200 + 5, 0xC7A, 0x60, 0x2FFF, Some(3));
+        let calendar = default_searcher();
+        assert!(calendar.search_seeds(2012, 6, 15, 12, 0, 0, 43210, 0xC7A, 0xC7A, 0x60, 0x60, &[first, second]).unwrap().is_empty());
+
+        // 固定: 全日を火曜日として扱う
+        let mut fixed = default_searcher();
+        fixed.set_weekday_override(2).unwrap();
+        assert_eq!(fixed.weekday_override(), Some(2));
+        let results = fixed.search_seeds(2012, 6, 15, 12, 0, 0, 43210, 0xC7A, 0xC7A, 0x60, 0x60, &[first, second]).unwrap();
+        assert_eq!(results.iter().map(|r| (r.seed(), r.weekday())).collect::<Vec<_>>(), vec![(first, Some(2))]);
+        assert!(results[0].verify());
+
+        // ずらす: 暦上の曜日から3日戻し、日付が進むと曜日も進む
+        let mut offset = default_searcher();
+        offset.set_weekday_offset(-3);
+        assert_eq!((offset.weekday_override(), offset.weekday_offset()), (None, 4));
+        let results = offset.search_seeds(2012, 6, 15, 12, 0, 0, 43210, 0xC7A, 0xC7A, 0x60, 0x60, &[first, second]).unwrap();
+        assert_eq!(results.iter().map(|r| (r.seed(), r.date(), r.weekday())).collect::<Vec<_>>(), vec![(first, 15, Some(2)), (second, 16, Some(3))]);
+        assert!(results.iter().all(|r| r.verify()));
+
+        // 曜日の規則が異なる探索器の指紋は一致しない（暦どおりの指紋は従来と同じ）
+        assert_ne!(offset.config_fingerprint(), calendar.config_fingerprint());
+        assert_ne!(fixed.config_fingerprint(), calendar.config_fingerprint());
+        let calendar_fingerprint = calendar.config_fingerprint();
+        assert_eq!(calendar.clone().with_weekday_rule(WeekdayRule::offset(7)).unwrap().config_fingerprint(), calendar_fingerprint);
+
+        // ネイティブAPIで直接組み立てた規則も検証・正規化される
+        assert_eq!(calendar.clone().with_weekday_rule(WeekdayRule::Fixed(9)).err(), Some(InitSeedError::InvalidWeekday(9)));
+        let wrapped = calendar.clone().with_weekday_rule(WeekdayRule::Offset(11)).unwrap();
+        assert_eq!(wrapped.weekday_rule(), WeekdayRule::Offset(4));
+        assert_eq!(wrapped.config_fingerprint(), offset.config_fingerprint());
+        assert_eq!(calendar.with_weekday_rule(WeekdayRule::Offset(7)).unwrap().config_fingerprint(), calendar_fingerprint);
+
+        // ジョブ仕様でも指定できる
+        let job = SearchJob::parse(&format!(r#"{{
+            "version": 1,
+            "profile": {{ "mac": "123456789ABC", "hardware": "DS", "weekday": {{ "offset": -3 }}, "nazo": {TEST_NAZO:?} }},
+            "datetime": {{ "start": "2012-06-15 12:00:00", "seconds": 43210 }},
+            "timer0": [3194, 3194],
+            "vcount": [96, 96],
+            "targets": {{ "seeds": [{first}, {second}] }}
+        }}"#)).unwrap();
+        assert_eq!(job.searcher().weekday_rule(), WeekdayRule::Offset(4));
+        assert_eq!(job.try_run().unwrap().len(), 2);
+        assert_eq!(
+            SearchJob::parse(&job.to_json().replace(r#""offset": -3"#, r#""fixed": 7"#)).err(),
+            Some(InitSeedError::InvalidWeekday(7)),
+        );
+    }
+
+    #[test]
+    fn test_list_seeds_packs_every_candidate() {
+        let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+        let key_inputs = searcher.key_inputs().to_vec();
+        assert_eq!(key_inputs, vec![0x2FFF, 0x2FFE]);
+
+        // 日付境界をまたぐ13秒 × Timer0 2種 × VCount 2種 × キー入力2種
+        let start = BASE_SECONDS + 12 * 3600 - 6;
+        let seeds = searcher.list_seeds(2012, 6, 15, 23, 59, 54, 13, 0xC79, 0xC7A, 0x5F, 0x60).unwrap();
+        assert_eq!(seeds.len(), 13 * 2 * 2 * 2);
+
+        let mut index = 0;
+        for timer0 in 0xC79..=0xC7A {
+            for vcount in 0x5F..=0x60 {
+                for &key_input in &key_inputs {
+                    for offset in 0..13 {
+                        assert_eq!(seeds[index], reference_seed_with_key(start + offset, timer0, vcount, key_input));
+                        index += 1;
+                    }
+                }
+            }
+        }
+
+        // 不正な日時と2000年より前はエラー
+        assert!(matches!(
+            searcher.list_seeds(2012, 2, 30, 0, 0, 0, 10, 0xC79, 0xC79, 0x60, 0x60),
+            Err(InitSeedError::InvalidDate { .. })
+        ));
+        assert_eq!(
+            searcher.list_seeds(1999, 12, 31, 23, 59, 59, 10, 0xC79, 0xC79, 0x60, 0x60),
+            Err(InitSeedError::DateOutOfRange)
+        );
+
+        // 候補数の積が溢れる範囲は確保を試みずにエラー
+        let all_keys = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x0FFF, 8).unwrap();
+        assert_eq!(
+            all_keys.list_seeds(2000, 1, 1, 0, 0, 0, 3_000_000_000, 0, 0xFFFF, 0, 0xFFFF),
+            Err(InitSeedError::SeedListTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_daily_window_search_matches_contiguous_search() {
+        let searcher = default_searcher();
+
+        // 2012-06-15〜17の各日 09:00:00〜09:00:19 と 21:30:00〜21:30:09（重複する時間帯を含む）
+        let morning = 9 * 3600;
+        let evening = 21 * 3600 + 30 * 60;
+        let day_base = BASE_SECONDS - 12 * 3600;
+        let mut targets = Vec::new();
+        for day in 0..3 {
+            let day_start = day_base + day * 86400;
+            targets.push(reference_seed(day_start + morning + 7 + day, 0xC79, 0x60));
+            targets.push(reference_seed(day_start + evening + 9 - day, 0xC79, 0x60));
+        }
+        // 時間帯外の秒はヒットしない
+        let outside = reference_seed(day_base + morning + 20, 0xC79, 0x60);
+        targets.push(outside);
+
+        let windows = [(evening, evening + 10), (morning, morning + 15), (morning + 10, morning + 20)];
+        let results = searcher
+            .search_seeds_daily_windows(2012, 6, 15, 2012, 6, 17, &windows, 0xC79, 0xC79, 0x60, 0x60, &targets)
+            .unwrap();
+
+        // 時間帯ごとの連続探索を日時順に連結したものと一致する
+        let mut expected = Vec::new();
+        for date in 15..=17 {
+            expected.extend(searcher.search_seeds(2012, 6, date, 9, 0, 0, 20, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap());
+            expected.extend(searcher.search_seeds(2012, 6, date, 21, 30, 0, 10, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap());
+        }
+        let key = |r: &crate::integrated_search::SearchResult| (r.seed(), r.date(), r.hour(), r.minute(), r.second());
+        assert_eq!(results.iter().map(key).collect::<Vec<_>>(), expected.iter().map(key).collect::<Vec<_>>());
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(|r| r.seed() != outside));
+
+        // 不正な時間帯はエラー
+        assert!(searcher.search_seeds_daily_windows(2012, 6, 15, 2012, 6, 17, &[(100, 100)], 0xC79, 0xC79, 0x60, 0x60, &targets).is_err());
+        assert!(searcher.search_seeds_daily_windows(2012, 6, 15, 2012, 6, 17, &[(0, 86401)], 0xC79, 0xC79, 0x60, 0x60, &targets).is_err());
+        // 終了日が開始日より前ならエラー
+        assert_eq!(
+            searcher.search_seeds_daily_windows(2012, 6, 17, 2012, 6, 15, &windows, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap_err(),
+            InitSeedError::ReversedDateRange
+        );
+        // 2099年を超える終了日は秒数がu32に収まらなくてもパニックせずエラー
+        assert_eq!(
+            searcher.search_seeds_daily_windows(2012, 6, 15, 2150, 1, 1, &windows, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap_err(),
+            InitSeedError::DateOutOfRange
+        );
+        assert_eq!(
+            searcher.search_seeds_daily_windows(2012, 6, 15, 2100, 1, 1, &windows, 0xC79, 0xC79, 0x60, 0x60, &targets).unwrap_err(),
+            InitSeedError::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_search_result_provenance_is_reproducible() {
+        let searcher = IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFE, 8).unwrap();
+        let target = reference_seed_with_key(BASE_SECONDS + 3, 0xC7A, 0x60, 0x2FFE);
+        let results = searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 8, 0xC7A, 0xC7A, 0x60, 0x60, &[target]).unwrap();
+        let hit = results.iter().find(|r| r.second() == 3).expect("hit");
+
+        assert_eq!(hit.mac(), TEST_MAC.to_vec());
+        assert_eq!(hit.nazo(), TEST_NAZO.to_vec());
+        assert_eq!(hit.hardware(), "DS");
+        assert_eq!(hit.frame(), 8);
+        assert_eq!(hit.key_input(), 0x2FFE);
+
+        // 記録されたメッセージからハッシュと64bitシードを再計算できる
+        let mut message = [0u32; 16];
+        message.copy_from_slice(&hit.message());
+        assert_eq!(message[12], swap_bytes_32(0x2FFE));
+        assert_eq!(message[9] & 0x40000000, 0x40000000, "DS afternoon PM flag");
+        let (h0, h1, h2, h3, h4) = calculate_pokemon_sha1(&message);
+        assert_eq!(hit.hash(), format!("{h0:08x}{h1:08x}{h2:08x}{h3:08x}{h4:08x}"));
+        let lcg_seed = crate::sha1::calculate_pokemon_lcg_seed_from_hash(h0, h1);
+        assert_eq!(hit.lcg_seed(), lcg_seed);
+        assert_eq!((lcg_seed.wrapping_mul(0x5D588B656C078965).wrapping_add(0x269EC3) >> 32) as u32, hit.seed());
+
+        assert!(hit.verify());
+
+        // 入力情報を持たない結果は検証できない
+        let mut bare = crate::integrated_search::SearchResult::new(hit.seed(), hit.hash(), 2012, 6, 15, 12, 0, 3, 0xC7A, 0x60);
+        assert_eq!(bare.key_input(), 0x2FFF);
+        bare.set_key_input(0x2FFE);
+        assert_eq!(bare.key_input(), 0x2FFE);
+        assert!(!bare.verify());
+    }
+
+    #[test]
+    fn test_searcher_built_from_hardware_profile() {
+        use crate::hardware::{Hardware, HardwareProfile};
+
+        // 種別名とフレーム値による構築はプロファイルによる構築と同じ探索器になる
+        let ds = HardwareProfile::new(Hardware::Ds);
+        let from_profile = IntegratedSeedSearcher::try_from_profile(&TEST_MAC, &TEST_NAZO, &ds, 0x2FFF).unwrap();
+        let from_name = default_searcher();
+        assert_eq!(from_profile.config_fingerprint(), from_name.config_fingerprint());
+        let target = reference_seed(BASE_SECONDS + 5, 0xC79, 0x60);
+        let hit = &from_profile.search_seeds(2012, 6, 15, 12, 0, 0, 10, 0xC79, 0xC79, 0x60, 0x60, &[target]).unwrap()[0];
+        assert_eq!(hit.hardware_profile(), Some(ds));
+        assert!(hit.verify());
+
+        // GxStat・フレーム値・PMフラグはメッセージに反映される
+        let custom = ds.with_gx_stat(0x06000100).with_frame(5).with_pm_flag(false);
+        let searcher = IntegratedSeedSearcher::try_from_profile(&TEST_MAC, &TEST_NAZO, &custom, 0x2FFF).unwrap();
+        assert_ne!(searcher.config_fingerprint(), from_profile.config_fingerprint());
+        let seeds = searcher.list_seeds(2012, 6, 15, 12, 0, 0, 1, 0xC79, 0xC79, 0x60, 0x60).unwrap();
+        let hit = &searcher.search_seeds(2012, 6, 15, 12, 0, 0, 1, 0xC79, 0xC79, 0x60, 0x60, &seeds).unwrap()[0];
+        let mac_upper = u32::from_le_bytes([TEST_MAC[0], TEST_MAC[1], TEST_MAC[2], TEST_MAC[3]]);
+        assert_eq!(hit.message()[7], swap_bytes_32(mac_upper ^ 0x06000100 ^ 5));
+        assert_eq!(hit.message()[9] & HardwareProfile::PM_FLAG, 0);
+        assert_eq!(hit.frame(), 5);
+        assert!(hit.verify());
+
+        // DSiは種別名でも構築できる
+        assert!(IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DSI", 0x2FFF, 8).is_ok());
+    }
+
+    #[test]
+    fn test_search_with_64bit_and_masked_targets() {
+        use crate::target_matcher::TargetMatcher;
+
+        let searcher = default_searcher();
+        let seed32 = reference_seed(BASE_SECONDS + 11, 0xC79, 0x60);
+        let by_seed32 = searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC79, 0x60, 0x60, &[seed32]).unwrap();
+        let hit = by_seed32.iter().find(|r| r.second() == 11).expect("32-bit hit");
+        let lcg_seed = hit.lcg_seed();
+
+        // 64bit初期シードでの完全一致
+        let by_seed64 = searcher.search_seeds_with_targets(
+            2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC79, 0x60, 0x60,
+            &TargetMatcher::from_seeds_u64(&[lcg_seed]),
+        ).unwrap();
+        assert_eq!(by_seed64.len(), 1);
+        assert_eq!((by_seed64[0].lcg_seed(), by_seed64[0].seed(), by_seed64[0].second()), (lcg_seed, seed32, 11));
+
+        // 64bit初期シードのマスク付き一致（下位48bit）
+        let by_mask64 = searcher.search_seeds_with_targets(
+            2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC79, 0x60, 0x60,
+            &TargetMatcher::masked_u64(lcg_seed, 0x0000_FFFF_FFFF_FFFF),
+        ).unwrap();
+        assert!(by_mask64.iter().any(|r| r.lcg_seed() == lcg_seed));
+        assert!(by_mask64.iter().all(|r| r.lcg_seed() & 0x0000_FFFF_FFFF_FFFF == lcg_seed & 0x0000_FFFF_FFFF_FFFF));
+
+        // 32bitシードのマスク付き一致（全ビット不問なら全候補がヒット）
+        let everything = searcher.search_seeds_with_targets(
+            2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC79, 0x60, 0x60,
+            &TargetMatcher::masked(0, 0),
+        ).unwrap();
+        assert_eq!(everything.len(), 20);
+        assert!(everything.iter().all(|r| r.verify()));
+    }
+
+    #[test]
+    fn test_search_with_incrementally_added_seeds() {
+        use crate::target_matcher::TargetMatcher;
+
+        // 実在のシードに無関係なシードを混ぜ、バケット索引の閾値をまたぐ件数を複数回に分けて追加する
+        let searcher = default_searcher();
+        let real: Vec<u32> = [3, 17, 29].iter().map(|&offset| reference_seed(BASE_SECONDS + offset, 0xC7A, 0x60)).collect();
+        let mut value = 0x2468ACE0u32;
+        let noise: Vec<u32> = (0..6000)
+            .map(|_| {
+                value = value.wrapping_mul(0x41C64E6D).wrapping_add(0x6073);
+                value
+            })
+            .collect();
+
+        let mut incremental = TargetMatcher::new();
+        incremental.add_seeds(&real[..1]);
+        for chunk in noise.chunks(1500) {
+            incremental.add_seeds(chunk);
+        }
+        incremental.add_seeds(&real[1..]);
+        incremental.add_seeds(&real);
+
+        let all: Vec<u32> = real.iter().chain(&noise).copied().collect();
+        let expected = searcher.search_seeds(2012, 6, 15, 12, 0, 0, 32, 0xC79, 0xC7A, 0x5F, 0x60, &all).unwrap();
+        let found = searcher.search_seeds_with_targets(2012, 6, 15, 12, 0, 0, 32, 0xC79, 0xC7A, 0x5F, 0x60, &incremental).unwrap();
+        let summary = |results: &[SearchResult]| results.iter().map(|r| (r.seed(), r.timer0(), r.vcount(), r.second())).collect::<Vec<_>>();
+        assert_eq!(summary(&found), summary(&expected));
+        assert!(real.iter().all(|seed| found.iter().any(|r| r.seed() == *seed)));
+    }
+
+    #[test]
+    fn test_search_hit_feeds_pokemon_generation() {
+        use crate::encounter_calculator::{EncounterType, GameVersion};
+        use crate::pokemon_generator::{BWGenerationConfig, PokemonGenerator};
+
+        let searcher = default_searcher();
+        let target = reference_seed(BASE_SECONDS + 2, 0xC79, 0x60);
+        let results = searcher.search_seeds_simd(2012, 6, 15, 12, 0, 0, 4, 0xC79, 0xC79, 0x60, 0x60, &[target]).unwrap();
+        let hit = results.iter().find(|r| r.second() == 2).expect("hit");
+
+        let config = BWGenerationConfig::new(GameVersion::BlackWhite, EncounterType::Normal, 12345, 54321, false, 0);
+        let batch = hit.generate_pokemon_batch(30, 5, &config);
+        let expected = PokemonGenerator::generate_pokemon_batch_bw(hit.lcg_seed(), 30, 5, &config);
+        assert_eq!(batch.len(), 5);
+        for (a, b) in batch.iter().zip(expected.iter()) {
+            assert_eq!((a.get_seed(), a.get_pid(), a.get_nature()), (b.get_seed(), b.get_pid(), b.get_nature()));
+        }
+
+        let mut enumerator = hit.pokemon_enumerator(30, 5, &config);
+        let first = enumerator.next_pokemon().expect("first pokemon");
+        assert_eq!(first.get_pid(), batch[0].get_pid());
+        assert_eq!(enumerator.remaining(), 4);
+    }
+
+    #[test]
+    fn test_compact_records_decode_to_search_results() {
+        use crate::multi_profile_search::{MultiProfileSearcher, SearchProfile};
+        use crate::result_records::{ResultRecord, RECORD_WORDS};
+        use crate::target_matcher::TargetMatcher;
+
+        let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+        let start = BASE_SECONDS + 12 * 3600 - 10;
+        let targets = [
+            reference_seed_with_key(start + 2, 0xC79, 0x60, 0x2FFE),
+            reference_seed_with_key(start + 17, 0xC7A, 0x5F, 0x2FFF),
+        ];
+        let matcher = TargetMatcher::from_seeds(&targets);
+
+        // レコード版は同じ順序で同じヒットを返し、展開すると再現用の入力まで一致する
+        let objects = searcher.search_seeds_with_targets(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x5F, 0x60, &matcher).unwrap();
+        let records = searcher.search_seed_records(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x5F, 0x60, &matcher).unwrap();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(records.len(), objects.len() * RECORD_WORDS);
+
+        let decoded = ResultRecord::decode_all(&records).unwrap();
+        assert_eq!(decoded[0].seconds_since_2000, start + 2);
+        assert_eq!((decoded[1].timer0, decoded[1].vcount, decoded[1].key_input), (0xC7A, 0x5F, 0x2FFF));
+
+        let expanded = searcher.try_decode_result_records(&records).unwrap();
+        for (object, result) in objects.iter().zip(&expanded) {
+            assert_eq!(
+                (result.seed(), result.lcg_seed(), result.hash(), result.message(), result.date(), result.second()),
+                (object.seed(), object.lcg_seed(), object.hash(), object.message(), object.date(), object.second())
+            );
+            assert!(result.verify());
+        }
+        assert!(searcher.try_decode_result_records(&records[1..]).is_err());
+
+        // 複数プロファイル探索ではプロファイル番号がレコードに入り、該当プロファイルで展開される
+        let mut multi = MultiProfileSearcher::new();
+        multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8, 0xC79, 0xC7A, 0x5F, 0x60).unwrap());
+        multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFE, 8, 0xC79, 0xC79, 0x60, 0x60).unwrap());
+        let records = multi.search_seed_records(2012, 6, 15, 23, 59, 50, 20, &matcher).unwrap();
+        let expanded = multi.try_decode_result_records(&records).unwrap();
+        let summary: Vec<_> = expanded.iter().map(|r| (r.profile_index(), r.seed(), r.key_input())).collect();
+        assert_eq!(summary, vec![(1, targets[0], 0x2FFE), (0, targets[1], 0x2FFF)]);
+        assert!(expanded.iter().all(|r| r.verify()));
+    }
 }
+
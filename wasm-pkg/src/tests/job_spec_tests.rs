@@ -0,0 +1,140 @@
+/// 探索ジョブ仕様のテストコード
+use crate::error::InitSeedError;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchResult};
+use crate::job_spec::SearchJob;
+use crate::rom_parameters::RomParameters;
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+#[test]
+fn test_search_job_spec_runs_like_direct_search() {
+    // 2012-06-15 23:59:54 から7秒後にキー入力0x2FFEで起動したときのシード
+    let seed = IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFE, 8).unwrap()
+        .list_seeds(2012, 6, 15, 23, 59, 54, 13, 0xC7A, 0xC7A, 0x60, 0x60).unwrap()[7];
+    let job_json = format!(r#"{{
+        "version": 1,
+        "profile": {{
+            "mac": "12:34:56:78:9A:BC",
+            "hardware": "DS",
+            "frame": 8,
+            "nazo": ["0x02215f10", "0x01000000", "0xc0000000", "0x00007fff", 0]
+        }},
+        "datetime": {{ "start": "2012-06-15T23:59:54", "seconds": 13 }},
+        "timer0": ["0xC79", "0xC7A"],
+        "vcount": [95, 96],
+        "keys": {{ "enumerate": "0x1" }},
+        "targets": {{ "seeds": [{seed}] }}
+    }}"#);
+
+    let job = SearchJob::parse(&job_json).unwrap();
+    let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+    assert_eq!(job.config_fingerprint(), searcher.config_fingerprint());
+
+    let summary = |results: &[SearchResult]| {
+        results.iter().map(|r| (r.date(), r.second(), r.timer0(), r.vcount(), r.key_input(), r.seed())).collect::<Vec<_>>()
+    };
+    let expected = searcher.search_seeds(2012, 6, 15, 23, 59, 54, 13, 0xC79, 0xC7A, 0x5F, 0x60, &[seed]).unwrap();
+    assert!(!expected.is_empty());
+    assert_eq!(summary(&job.try_run().unwrap()), summary(&expected));
+
+    // 書き出したJSONから同じジョブを復元できる
+    let restored = SearchJob::parse(&job.to_json()).unwrap();
+    assert_eq!(restored.to_json(), job.to_json());
+    assert_eq!(summary(&restored.try_run().unwrap()), summary(&expected));
+
+    // 日次時間帯指定でも同じ秒を探索できる
+    let daily_json = job_json.replace(
+        r#""datetime": { "start": "2012-06-15T23:59:54", "seconds": 13 }"#,
+        r#""daily_windows": { "start_date": "2012-06-15", "end_date": "2012-06-16", "windows": [[86394, 86400], [0, 7]] }"#,
+    );
+    assert_eq!(summary(&SearchJob::parse(&daily_json).unwrap().try_run().unwrap()), summary(&expected));
+
+    // ROM指定ではnazoとTimer0/VCount範囲をパラメータ表から補う
+    let rom_json = job_json
+        .replace(r#""nazo": ["0x02215f10", "0x01000000", "0xc0000000", "0x00007fff", 0]"#, r#""rom": { "version": "B2", "region": "GER" }"#)
+        .replace(r#""timer0": ["0xC79", "0xC7A"],"#, "")
+        .replace(r#""vcount": [95, 96],"#, "");
+    let rom_job = SearchJob::parse(&rom_json).unwrap();
+    let rom = RomParameters::parse("B2", "GER", "DS").unwrap();
+    let rom_searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, rom.nazo_words(), "DS", 0x1, 8).unwrap();
+    assert_eq!(rom_job.config_fingerprint(), rom_searcher.config_fingerprint());
+    assert!(rom_job.try_run().unwrap().is_empty());
+
+    // 検証エラー
+    assert_eq!(
+        SearchJob::parse(&job_json.replace(r#""version": 1"#, r#""version": 2"#)).err(),
+        Some(InitSeedError::UnsupportedJobVersion(2)),
+    );
+    assert_eq!(
+        SearchJob::parse(&job_json.replace(r#""vcount": [95, 96]"#, r#""vcount": [96, 95]"#)).err(),
+        Some(InitSeedError::InvalidVCountRange { min: 96, max: 95 }),
+    );
+    assert_eq!(
+        SearchJob::parse(&daily_json.replace(r#""end_date": "2012-06-16""#, r#""end_date": "2150-01-01""#)).err(),
+        Some(InitSeedError::DateOutOfRange),
+    );
+    assert_eq!(
+        SearchJob::parse(&job_json.replace("2012-06-15T23", "2012-02-30T23")).err().map(|e| e.code()),
+        Some("INVALID_DATE"),
+    );
+    for broken in [
+        job_json.replace(r#""frame": 8"#, r#""frame": 8, "colour": 1"#),
+        job_json.replace(r#""timer0": ["0xC79", "0xC7A"],"#, ""),
+        job_json.replace(r#""enumerate": "0x1""#, r#""hold": "0x1""#),
+        job_json.replace(&format!("[{seed}]"), "[]"),
+        job_json.replace(&format!("[{seed}]"), r#"["0x+1"]"#),
+        job_json.replace("9A:BC", "9A"),
+    ] {
+        assert_eq!(SearchJob::parse(&broken).err().map(|e| e.code()), Some("INVALID_JOB_SPEC"), "{broken}");
+    }
+    // 負の数・小数はフィールド名付きで報告する
+    for (broken, field) in [
+        (job_json.replace(r#""timer0": ["0xC79", "0xC7A"]"#, r#""timer0": [-1, "0xC7A"]"#), "timer0"),
+        (job_json.replace(r#""vcount": [95, 96]"#, r#""vcount": [95.5, 96]"#), "vcount"),
+        (job_json.replace(r#""enumerate": "0x1""#, r#""enumerate": -3"#), "keys.enumerate"),
+    ] {
+        let message = SearchJob::parse(&broken).err().expect("rejected").to_string();
+        assert!(message.contains(&format!("{field}: ")), "{message}");
+    }
+}
+
+#[test]
+fn test_search_job_spec_accepts_hex_in_every_numeric_field() {
+    // 2012-06-15 23:59:54 から7秒後（6/16 土曜日）に起動したときのシード
+    let seed = default_searcher()
+        .list_seeds(2012, 6, 15, 23, 59, 54, 13, 0xC7A, 0xC7A, 0x60, 0x60).unwrap()[7];
+    let job_json = |frame: &str, weekday: &str, span: &str| format!(r#"{{
+        "version": 1,
+        "profile": {{ "mac": "123456789ABC", "hardware": "DS", "frame": {frame}, "weekday": {{ "fixed": {weekday} }}, "nazo": {TEST_NAZO:?} }},
+        {span},
+        "timer0": [3194, 3194],
+        "vcount": [96, 96],
+        "targets": {{ "seeds": [{seed}] }}
+    }}"#);
+    let continuous = |seconds: &str| format!(r#""datetime": {{ "start": "2012-06-15T23:59:54", "seconds": {seconds} }}"#);
+    let daily = |last: &str| format!(
+        r#""daily_windows": {{ "start_date": "2012-06-15", "end_date": "2012-06-15", "windows": [[86394, {last}]] }}"#
+    );
+
+    // フレーム値・固定曜日・秒数・時間帯は10進数でも16進文字列でも同じジョブになる
+    let summary = |json: String| {
+        let job = SearchJob::parse(&json).unwrap();
+        let results = job.try_run().unwrap();
+        (job.config_fingerprint(), results.iter().map(|r| (r.second(), r.seed())).collect::<Vec<_>>())
+    };
+    let decimal = summary(job_json("8", "6", &continuous("13")));
+    assert_eq!(decimal.1, vec![(1, seed)]);
+    assert_eq!(summary(job_json(r#""0x8""#, r#""0x6""#, &continuous(r#""0xD""#))), decimal);
+    assert_eq!(summary(job_json("8", "6", &daily(r#""0x15180""#))), summary(job_json("8", "6", &daily("86400"))));
+
+    // 負の数・範囲外の値・符号付きの16進文字列はフィールド名付きで報告する
+    for (broken, field) in [
+        (job_json("-8", "6", &continuous("13")), "profile.frame"),
+        (job_json("8", r#""0x100000000""#, &continuous("13")), "profile.weekday.fixed"),
+        (job_json(r#""0x+8""#, "6", &continuous("13")), "profile.frame"),
+        (job_json("8", "6", &continuous("13.5")), "datetime.seconds"),
+        (job_json("8", "6", &daily(r#""86400s""#)), "daily_windows.windows"),
+    ] {
+        let message = SearchJob::parse(&broken).err().expect("rejected").to_string();
+        assert!(message.contains(&format!("{field}: ")), "{message}");
+    }
+}
@@ -4,3 +4,23 @@ pub mod integrated_generation_tests;
 pub mod sha1_tests;
 pub mod sha1_simd_tests;
 pub mod datetime_codes_tests;
+
+// 機能別テスト（ネイティブ環境のみ）
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod fixtures;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod multi_profile_search_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod search_control_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod calibration_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod robustness_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod seed_index_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod job_spec_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod work_plan_tests;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tid_sid_target_tests;
@@ -0,0 +1,54 @@
+/// 複数プロファイル探索のテストコード
+use crate::hardware::{Hardware, HardwareProfile};
+use crate::integrated_search::{IntegratedSeedSearcher, SearchResult};
+use crate::multi_profile_search::{MultiProfileSearcher, SearchProfile};
+use crate::rom_parameters::{RomRegion, RomVersion};
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO};
+
+#[test]
+fn test_multi_profile_search_tags_profile_index() {
+    let mut multi = MultiProfileSearcher::new();
+    assert_eq!(multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFE, 8, 0xC79, 0xC7A, 0x60, 0x60).unwrap()), 0);
+    assert_eq!(multi.add_profile(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8, 0xC79, 0xC79, 0x5F, 0x60).unwrap()), 1);
+    assert_eq!(multi.profile_count(), 2);
+
+    // 23:59:50 から20秒間（日付境界をまたぐ）で各プロファイルのシードを1つずつ探す
+    let seed_at = |key_input, offset: usize, timer0, vcount| {
+        IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", key_input, 8).unwrap()
+            .list_seeds(2012, 6, 15, 23, 59, 50, 20, timer0, timer0, vcount, vcount).unwrap()[offset]
+    };
+    let first = seed_at(0x2FFE, 3, 0xC7A, 0x60);
+    let second = seed_at(0x2FFF, 15, 0xC79, 0x5F);
+    let results = multi.search_seeds(2012, 6, 15, 23, 59, 50, 20, &[second, first]).unwrap();
+
+    let first_hit = results.iter().position(|r| r.seed() == first).expect("profile 0 hit");
+    let second_hit = results.iter().position(|r| r.seed() == second).expect("profile 1 hit");
+    assert!(first_hit < second_hit, "results should be ordered by date first");
+
+    let hit = &results[first_hit];
+    assert_eq!((hit.profile_index(), hit.key_input(), hit.timer0(), hit.date(), hit.second()), (0, 0x2FFE, 0xC7A, 15, 53));
+    let hit = &results[second_hit];
+    assert_eq!((hit.profile_index(), hit.key_input(), hit.vcount(), hit.date(), hit.second()), (1, 0x2FFF, 0x5F, 16, 5));
+
+    assert!(SearchProfile::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFF, 8, 0xC7A, 0xC79, 0x60, 0x60).is_err());
+}
+
+#[test]
+fn test_rom_profiles_rank_with_the_whole_rom_prior() {
+    // B2 ITAはVCount 0x82でTimer0が3通り、0x83で5通り。ROM表の事前分布では8セルとも等確率
+    let mut multi = MultiProfileSearcher::new();
+    let ds = HardwareProfile::new(Hardware::Ds);
+    let added = multi.add_rom_profiles(&TEST_MAC, RomVersion::Black2, RomRegion::Ita, &ds, 0x2FFF).unwrap();
+    assert_eq!(added, 2);
+
+    // VCount 0x83（セル数の多いプロファイル）のヒットを先に並べても、順位付けで入れ替わらない
+    let hit = |profile_index: u32, timer0: u32, vcount: u32| {
+        let mut result = SearchResult::new(0, String::new(), 2012, 6, 15, 12, 0, 0, timer0, vcount);
+        result.set_profile_index(profile_index);
+        result
+    };
+    let mut results = vec![hit(1, 0x1109, 0x83), hit(1, 0x110D, 0x83), hit(0, 0x1107, 0x82), hit(0, 0x1109, 0x82)];
+    let before: Vec<_> = results.iter().map(|r| (r.profile_index(), r.timer0(), r.vcount())).collect();
+    multi.rank_results(&mut results);
+    assert_eq!(results.iter().map(|r| (r.profile_index(), r.timer0(), r.vcount())).collect::<Vec<_>>(), before);
+}
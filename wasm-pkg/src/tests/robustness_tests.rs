@@ -0,0 +1,38 @@
+/// 起動タイミングの頑健性評価のテストコード
+use crate::integrated_search::SearchResult;
+use crate::error::InitSeedError;
+use crate::robustness::{RobustnessWindow, MAX_ROBUSTNESS_RADIUS};
+use crate::target_matcher::TargetMatcher;
+use crate::tests::fixtures::default_searcher;
+
+#[test]
+fn test_robustness_scores_neighbouring_boot_timings() {
+    let searcher = default_searcher();
+    // 12:00:10～12:00:12の連続3秒と、孤立した12:00:30をターゲットにする
+    let all = searcher.list_seeds(2012, 6, 15, 12, 0, 0, 40, 0xC7A, 0xC7A, 0x60, 0x60).unwrap();
+    let seeds: Vec<u32> = [10, 11, 12, 30].iter().map(|&offset| all[offset]).collect();
+    let targets = TargetMatcher::from_seeds(&seeds);
+
+    let mut results = searcher.search_seeds_with_targets(2012, 6, 15, 12, 0, 0, 40, 0xC7A, 0xC7A, 0x60, 0x60, &targets).unwrap();
+    assert!(results.iter().all(|r| r.robustness_score().is_none()));
+
+    // 前後1秒 × Timer0前後1の8通りを評価する
+    let window = RobustnessWindow::try_new(1, 1, 0).unwrap();
+    searcher.score_robustness(&mut results, &targets, &window);
+    let scored: Vec<_> = results.iter().map(|r| (r.second(), r.robustness_hits().unwrap(), r.robustness_neighbours().unwrap())).collect();
+    assert_eq!(scored, vec![(10, 1, 8), (11, 2, 8), (12, 1, 8), (30, 0, 8)]);
+
+    SearchResult::sort_by_robustness(&mut results);
+    assert_eq!(results.iter().map(|r| r.second()).collect::<Vec<_>>(), vec![11, 10, 12, 30]);
+    assert_eq!(results[0].robustness_score(), Some(0.25));
+}
+
+#[test]
+fn test_robustness_window_rejects_large_radius() {
+    assert!(RobustnessWindow::try_new(MAX_ROBUSTNESS_RADIUS, MAX_ROBUSTNESS_RADIUS, MAX_ROBUSTNESS_RADIUS).is_ok());
+    assert_eq!(
+        RobustnessWindow::try_new(1, MAX_ROBUSTNESS_RADIUS + 1, 0).err(),
+        Some(InitSeedError::RobustnessRadiusTooLarge(MAX_ROBUSTNESS_RADIUS + 1))
+    );
+    assert_eq!(RobustnessWindow::try_new(u32::MAX, 0, 0).err(), Some(InitSeedError::RobustnessRadiusTooLarge(u32::MAX)));
+}
@@ -0,0 +1,129 @@
+/// 中断可能な探索のテストコード
+use crate::integrated_search::{IntegratedSeedSearcher, SearchResult};
+use crate::search_control::{CancellationToken, SearchCursor, SearchPosition};
+use crate::target_matcher::TargetMatcher;
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+#[test]
+fn test_cancellable_search_reports_progress_and_partial_results() {
+    let searcher = default_searcher();
+    // 23:59:50 から20秒間 → 日付区間2つ × Timer0 2種 × VCount 1種 = 4単位
+    // 2012-06-15 23:59:50 の2000年からの経過秒
+    let start = 1339804790 - 946684800;
+    let targets = searcher.list_seeds(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60).unwrap();
+    let matcher = TargetMatcher::from_seeds(&targets);
+
+    // 最後まで実行: 単位ごとに進捗が通知される
+    let token = CancellationToken::new();
+    let mut progress_log = Vec::new();
+    let outcome = searcher.search_seeds_cancellable(
+        2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60, &matcher, &token,
+        |progress| progress_log.push((progress.completed_units, progress.total_units, progress.results_found)),
+    ).unwrap();
+    assert!(!outcome.cancelled());
+    assert_eq!(outcome.stopped_at(), None);
+    assert_eq!((outcome.completed_units(), outcome.total_units()), (4, 4));
+    assert_eq!(progress_log, vec![(1, 4, 10), (2, 4, 20), (3, 4, 30), (4, 4, 40)]);
+    assert_eq!(outcome.result_list().len(), 40);
+
+    // 2単位目の処理後にキャンセル: 途中結果と次の探索単位の位置を返す
+    let token = CancellationToken::new();
+    let outcome = searcher.search_seeds_cancellable(
+        2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60, &matcher, &token,
+        |progress| {
+            if progress.completed_units == 2 {
+                token.cancel();
+            }
+        },
+    ).unwrap();
+    assert!(outcome.cancelled());
+    assert_eq!(outcome.completed_units(), 2);
+    assert_eq!(
+        outcome.stopped_at(),
+        Some(SearchPosition { timer0: 0xC7A, vcount: 0x60, key_input: 0x2FFF, seconds_since_2000: start })
+    );
+    let partial = outcome.into_results();
+    assert_eq!(partial.len(), 20);
+    assert!(partial.iter().all(|r| r.timer0() == 0xC79));
+
+    // 開始前にキャンセル済みなら何も処理しない
+    let token = CancellationToken::new();
+    token.cancel();
+    let outcome = searcher.search_seeds_cancellable(
+        2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60, &matcher, &token, |_| {},
+    ).unwrap();
+    assert_eq!((outcome.cancelled(), outcome.completed_units()), (true, 0));
+
+    // 探索単位の総数は32bitを超えても切り詰めない（Timer0・VCount全域 × 2日）
+    let outcome = searcher.search_seeds_cancellable(
+        2012, 6, 15, 23, 59, 50, 20, 0, 0xFFFF, 0, 0xFFFF, &matcher, &token, |_| {},
+    ).unwrap();
+    assert_eq!((outcome.cancelled(), outcome.total_units()), (true, 2 * 65536 * 65536));
+    let serialized = outcome.cursor().unwrap().serialize();
+    let (head, _) = serialized.rsplit_once(':').unwrap();
+    let beyond_u32 = SearchCursor::parse(&format!("{head}:{}", 1u64 << 32)).unwrap();
+    assert_eq!(beyond_u32.next_unit(), 1 << 32);
+    token.reset();
+    assert!(!token.is_cancelled());
+}
+
+#[test]
+fn test_resume_from_checkpoint() {
+    let searcher = default_searcher();
+    let targets = searcher.list_seeds(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60).unwrap();
+    let matcher = TargetMatcher::from_seeds(&targets);
+
+    // 3単位目の処理後に中断し、進捗で受け取ったカーソルを文字列として保存
+    let token = CancellationToken::new();
+    let mut saved = None;
+    let first = searcher.search_seeds_cancellable(
+        2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60, &matcher, &token,
+        |progress| {
+            saved = Some(progress.cursor.serialize());
+            if progress.completed_units == 3 {
+                token.cancel();
+            }
+        },
+    ).unwrap();
+    let saved = saved.unwrap();
+    assert_eq!(first.cursor().map(|cursor| cursor.serialize()), Some(saved.clone()));
+
+    // 復元したカーソルから再開すると残りの単位のみを処理し、結果を合わせると全件になる
+    let cursor = SearchCursor::parse(&saved).unwrap();
+    assert_eq!(cursor.next_unit(), 3);
+    let token = CancellationToken::new();
+    let mut progress_log = Vec::new();
+    let resumed = searcher
+        .resume_search(&cursor, &matcher, &token, |progress| progress_log.push(progress.completed_units))
+        .unwrap();
+    assert!(resumed.cursor().is_none());
+    assert_eq!(progress_log, vec![4]);
+    assert_eq!((resumed.completed_units(), resumed.total_units()), (4, 4));
+
+    let mut combined = first.into_results();
+    combined.extend(resumed.into_results());
+    let full = searcher.search_seeds_simd(2012, 6, 15, 23, 59, 50, 20, 0xC79, 0xC7A, 0x60, 0x60, &targets).unwrap();
+    let summarize = |results: &[SearchResult]| results.iter().map(|r| (r.seed(), r.timer0(), r.second())).collect::<Vec<_>>();
+    assert_eq!(summarize(&combined), summarize(&full));
+
+    // MAC・nazo・キー入力設定が異なる探索器ではチェックポイントを拒否する
+    let mut other_mac = TEST_MAC;
+    other_mac[5] ^= 1;
+    let mut other_nazo = TEST_NAZO;
+    other_nazo[0] ^= 1;
+    let others = [
+        IntegratedSeedSearcher::try_new(&other_mac, &TEST_NAZO, "DS", 0x2FFF, 8).unwrap(),
+        IntegratedSeedSearcher::try_new(&TEST_MAC, &other_nazo, "DS", 0x2FFF, 8).unwrap(),
+        IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", 0x2FFE, 8).unwrap(),
+        IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x0003, 8).unwrap(),
+    ];
+    for other in &others {
+        assert_ne!(other.config_fingerprint(), searcher.config_fingerprint());
+        assert!(other.resume_search(&cursor, &matcher, &token, |_| {}).is_err());
+    }
+
+    // 不正な文字列・未知のバージョンは読み込めない
+    assert!(SearchCursor::parse("v0:0:0:0:0:0:0:0:0").is_err());
+    assert!(SearchCursor::parse(&saved.replace(':', ";")).is_err());
+    assert!(SearchCursor::parse("v1:xyz:0:0:0:0:0:0:0").is_err());
+}
@@ -0,0 +1,65 @@
+/// 事前計算シードインデックスのテストコード
+use crate::error::InitSeedError;
+use crate::integrated_search::IntegratedSeedSearcher;
+use crate::seed_index::SeedIndex;
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+#[test]
+fn test_seed_index_answers_lookups_without_rehashing() {
+    // 日付境界をまたぐ13秒 × Timer0 2種 × VCount 2種 × キー入力2種
+    let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+    let index = SeedIndex::try_build(&searcher, 2012, 6, 15, 23, 59, 54, 13, 0xC79, 0xC7A, 0x5F, 0x60).unwrap();
+    assert_eq!(index.entry_count(), 13 * 2 * 2 * 2);
+    assert!(index.matches_searcher(&searcher));
+
+    // バイナリ形式で保存・復元しても同じ結果を返す
+    let bytes = index.to_bytes();
+    let restored = SeedIndex::read_from(&mut bytes.as_slice()).unwrap();
+    assert_eq!(restored, index);
+
+    // 2012-06-15 23:59:54 の2000年からの経過秒
+    let start = 1339804794 - 946684800;
+    let seed_at = |key_input, offset: usize, timer0, vcount| {
+        IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", key_input, 8).unwrap()
+            .list_seeds(2012, 6, 15, 23, 59, 54, 21, timer0, timer0, vcount, vcount).unwrap()[offset]
+    };
+    for (offset, timer0, vcount, key_input) in [(0, 0xC79, 0x5F, 0x2FFF), (7, 0xC7A, 0x60, 0x2FFE), (12, 0xC79, 0x60, 0x2FFE)] {
+        let seed = seed_at(key_input, offset, timer0, vcount);
+        let searched = searcher.search_seeds(2012, 6, 15, 23, 59, 54, 13, 0xC79, 0xC7A, 0x5F, 0x60, &[seed]).unwrap();
+        let hits = restored.find(seed);
+        assert_eq!(
+            hits.iter().map(|h| (h.date(), h.second(), h.timer0(), h.vcount(), h.key_input(), h.seed())).collect::<Vec<_>>(),
+            searched.iter().map(|r| (r.date(), r.second(), r.timer0(), r.vcount(), r.key_input(), r.seed())).collect::<Vec<_>>(),
+        );
+        assert!(hits.iter().any(|h| h.seconds_since_2000() == (start + offset) as i64));
+    }
+    assert!(restored.find(seed_at(0x2FFF, 20, 0xC79, 0x5F)).is_empty());
+
+    // 壊れたデータ・別設定の探索器
+    assert_eq!(SeedIndex::parse(&bytes[..bytes.len() - 1]), Err(InitSeedError::InvalidSeedIndex));
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] = 2;
+    assert_eq!(SeedIndex::parse(&wrong_version), Err(InitSeedError::InvalidSeedIndex));
+    let other = default_searcher();
+    assert!(!index.matches_searcher(&other));
+}
+
+#[test]
+fn test_seed_index_rejects_candidate_counts_beyond_u64() {
+    // Timer0・VCount全域 × キー入力2種 × 約95年分の秒はu64に収まらない
+    let range_seconds = 3_000_000_000u32;
+    let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+    assert_eq!(
+        SeedIndex::try_build(&searcher, 2000, 1, 1, 0, 0, 0, range_seconds, 0, 0xFFFF, 0, 0xFFFF),
+        Err(InitSeedError::SeedIndexTooLarge),
+    );
+
+    // 同じ範囲を書き込んだヘッダは読み込み時に拒否する
+    let index = SeedIndex::try_build(&searcher, 2012, 6, 15, 12, 0, 0, 1, 0xC79, 0xC79, 0x60, 0x60).unwrap();
+    let mut bytes = index.to_bytes();
+    bytes[16..24].copy_from_slice(&0u64.to_le_bytes());
+    for (offset, value) in [(24, range_seconds), (28, 0), (32, 0xFFFF), (36, 0), (40, 0xFFFF)] {
+        bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+    assert_eq!(SeedIndex::parse(&bytes), Err(InitSeedError::InvalidSeedIndex));
+}
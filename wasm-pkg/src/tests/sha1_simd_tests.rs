@@ -1,5 +1,5 @@
 /// SHA-1 SIMD実装のテストコード
-use crate::sha1_simd::calculate_pokemon_sha1_simd;
+use crate::sha1_simd::full_message::{calculate_pokemon_sha1_simd, calculate_pokemon_sha1_simd_x8};
 
 #[cfg(test)]
 mod tests {
@@ -228,4 +228,65 @@ mod tests {
             assert_ne!(results[base_idx + 4], 0, "h4 is 0 for message {}", i);
         }
     }
+
+    #[test]
+    fn test_simd_x8_matches_scalar() {
+        // 8組の異なるメッセージ（ポケモンメッセージ形式）でテスト
+        let mut messages = [0u32; 128];
+        for lane in 0..8u32 {
+            let message = [
+                0x02215f10, 0x01000000, 0xc0000000, 0x00007fff,
+                0x00000000, 0x600c7900u32.wrapping_add(lane), 0x00009abc, 0x12345678 ^ lane,
+                0x12061505, 0x12000000 | (lane << 8), 0x00000000, 0x00000000,
+                0xff2f0000, 0x80000000, 0x00000000, 0x000001a0,
+            ];
+            let base = lane as usize * 16;
+            messages[base..base + 16].copy_from_slice(&message);
+        }
+
+        let simd_results = calculate_pokemon_sha1_simd_x8(&messages);
+
+        for lane in 0..8 {
+            let mut message = [0u32; 16];
+            message.copy_from_slice(&messages[lane * 16..(lane + 1) * 16]);
+            let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1(&message);
+            assert_eq!(&simd_results[lane * 5..lane * 5 + 5], &[h0, h1, h2, h3, h4], "hash mismatch for lane {}", lane);
+        }
+
+        // 前半・後半の4組が4レーン実装と一致すること
+        let mut first_half = [0u32; 64];
+        first_half.copy_from_slice(&messages[..64]);
+        assert_eq!(&simd_results[..20], &calculate_pokemon_sha1_simd(&first_half));
+    }
+
+    #[test]
+    fn test_simd_prefix_matches_scalar() {
+        use crate::sha1::Sha1Prefix;
+        use crate::sha1_simd::{calculate_pokemon_sha1_simd_with_prefix, calculate_pokemon_sha1_simd_x8_with_prefix};
+
+        let mut message = [
+            0x02215f10, 0x01000000, 0xc0000000, 0x00007fff,
+            0x00000000, 0x600c7900, 0x00009abc, 0x12345678,
+            0x12061505, 0x00000000, 0x00000000, 0x00000000,
+            0xff2f0000, 0x80000000, 0x00000000, 0x000001a0,
+        ];
+        let prefix = Sha1Prefix::new(&message);
+
+        let mut time_codes = [0u32; 8];
+        for (i, time_code) in time_codes.iter_mut().enumerate() {
+            *time_code = 0x52000000 | ((i as u32) << 8);
+        }
+
+        let x8_results = calculate_pokemon_sha1_simd_x8_with_prefix(&prefix, &time_codes);
+        for (lane, &time_code) in time_codes.iter().enumerate() {
+            message[9] = time_code;
+            let (h0, h1, h2, h3, h4) = crate::sha1::calculate_pokemon_sha1(&message);
+            assert_eq!(&x8_results[lane * 5..lane * 5 + 5], &[h0, h1, h2, h3, h4], "hash mismatch for lane {}", lane);
+        }
+
+        // 4レーン版も同じ結果になること
+        let mut second_half = [0u32; 4];
+        second_half.copy_from_slice(&time_codes[4..]);
+        assert_eq!(&x8_results[20..], &calculate_pokemon_sha1_simd_with_prefix(&prefix, &second_half));
+    }
 }
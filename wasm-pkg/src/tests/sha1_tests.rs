@@ -178,6 +178,7 @@ mod native_tests {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]  // 定義式をそのまま書き下して検証する
     fn test_sha1_functions() {
         assert_eq!(choice(0xFFFFFFFF, 0x12345678, 0x9ABCDEF0), 0x12345678);
         
@@ -263,4 +264,51 @@ mod native_tests {
         let seed_2 = calculate_pokemon_seed_from_hash(h0_2, h1_2);
         assert_eq!(seed_1, seed_2);
     }
+
+    #[test]
+    fn test_sha1_prefix_matches_full() {
+        use crate::sha1::{calculate_pokemon_sha1_with_prefix, Sha1Prefix};
+
+        // 統合探索と同じメッセージ形式（data[9]のみ変化させる）
+        let mut message = [
+            0x02215f10, 0x01000000, 0xc0000000, 0x00007fff,
+            0x00000000, 0x600c7900, 0x00009abc, 0x12345678,
+            0x12061505, 0x00000000, 0x00000000, 0x00000000,
+            0xff2f0000, 0x80000000, 0x00000000, 0x000001a0,
+        ];
+        // data[9]の値はPrefix構築時に無視される
+        message[9] = 0xdeadbeef;
+        let prefix = Sha1Prefix::new(&message);
+
+        for time_code in [0x00000000, 0x12345600, 0x52595900, 0xffffffff] {
+            message[9] = time_code;
+            assert_eq!(
+                calculate_pokemon_sha1_with_prefix(&prefix, time_code),
+                calculate_pokemon_sha1(&message),
+                "hash mismatch for time code {:08x}", time_code
+            );
+        }
+    }
+
+    #[test]
+    fn test_variable_schedule_words_cover_data9_dependencies() {
+        use crate::sha1::{Sha1Prefix, VARIABLE_SCHEDULE_FULL_START, VARIABLE_SCHEDULE_WORDS_EARLY};
+
+        // data[9]のみ異なる2つのメッセージを展開し、差分が出る位置を列挙する
+        // 1bitだけ立てることで回転量の異なる項どうしが打ち消し合わないようにする
+        let mut message = [0u32; 16];
+        let base = Sha1Prefix::new(&message).schedule;
+        message[9] = 0x00000001;
+        let mut flipped = [0u32; 80];
+        flipped[..16].copy_from_slice(&message);
+        for i in 16..80 {
+            flipped[i] = (flipped[i-3] ^ flipped[i-8] ^ flipped[i-14] ^ flipped[i-16]).rotate_left(1);
+        }
+
+        let dependent: Vec<usize> = (16..80).filter(|&i| base[i] != flipped[i]).collect();
+        let expected: Vec<usize> = VARIABLE_SCHEDULE_WORDS_EARLY.iter().copied()
+            .chain(VARIABLE_SCHEDULE_FULL_START..80)
+            .collect();
+        assert_eq!(dependent, expected);
+    }
 }
@@ -0,0 +1,63 @@
+/// TID/SID条件探索のテストコード
+use crate::error::InitSeedError;
+use crate::offset_calculator::{calculate_tid_sid_from_seed, GameMode};
+use crate::pid_shiny_checker::ShinyChecker;
+use crate::target_matcher::TargetMatcher;
+use crate::tid_sid_target::TidSidTarget;
+use crate::tests::fixtures::default_searcher;
+
+#[test]
+fn test_tid_sid_search_matches_offset_calculator() {
+    let searcher = default_searcher();
+    let search = |target: &TidSidTarget| {
+        searcher.search_tid_sid(2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC7A, 0x60, 0x60, GameMode::BwNewGameNoSave, target).unwrap()
+    };
+
+    // 全候補のLCGシードとTID/SID（シード全域を照合する通常の探索から求める）
+    let mut everything = TargetMatcher::new();
+    everything.add_range(0, u32::MAX);
+    let all: Vec<_> = searcher
+        .search_seeds_with_targets(2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC7A, 0x60, 0x60, &everything)
+        .unwrap()
+        .iter()
+        .map(|r| (r.lcg_seed(), calculate_tid_sid_from_seed(r.lcg_seed(), GameMode::BwNewGameNoSave)))
+        .collect();
+    assert_eq!(all.len(), 40);
+
+    let (chosen_seed, chosen) = &all[27];
+    let (tid, sid) = (chosen.tid, chosen.sid);
+    let mut by_tid = TidSidTarget::new();
+    by_tid.set_tid(tid);
+    let hits = search(&by_tid);
+    assert_eq!(
+        hits.iter().map(|r| r.lcg_seed()).collect::<Vec<_>>(),
+        all.iter().filter(|(_, ids)| ids.tid == tid).map(|&(seed, _)| seed).collect::<Vec<_>>(),
+    );
+    for result in &hits {
+        let ids = calculate_tid_sid_from_seed(result.lcg_seed(), GameMode::BwNewGameNoSave);
+        assert_eq!((result.tid(), result.sid()), (Some(ids.tid), Some(ids.sid)));
+        assert!(result.verify());
+    }
+
+    // TID/SIDでこのPIDが色違いになる起動条件
+    let mut by_shiny = TidSidTarget::new();
+    by_shiny.add_shiny_pid(((tid ^ sid) as u32) << 16);
+    let hits = search(&by_shiny);
+    assert!(hits.iter().any(|r| r.lcg_seed() == *chosen_seed));
+    assert_eq!(
+        hits.len(),
+        all.iter().filter(|(_, ids)| ShinyChecker::is_shiny(ids.tid, ids.sid, ((tid ^ sid) as u32) << 16)).count(),
+    );
+
+    // 条件のないターゲットは全候補に一致するため受け付けない
+    assert_eq!(
+        searcher.search_tid_sid(2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC7A, 0x60, 0x60, GameMode::BwNewGameNoSave, &TidSidTarget::new()).err(),
+        Some(InitSeedError::EmptyTidSidTarget),
+    );
+
+    // 「続きから」ではTID/SIDが決まらない
+    assert_eq!(
+        searcher.search_tid_sid(2012, 6, 15, 12, 0, 0, 20, 0xC79, 0xC7A, 0x60, 0x60, GameMode::BwContinue, &by_tid).err(),
+        Some(InitSeedError::NoTidSidForMode(2)),
+    );
+}
@@ -0,0 +1,77 @@
+/// 分散探索の作業計画のテストコード
+use crate::error::InitSeedError;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchResult};
+use crate::target_matcher::TargetMatcher;
+use crate::work_plan::WorkPlan;
+use crate::tests::fixtures::{TEST_MAC, TEST_NAZO, default_searcher};
+
+#[test]
+fn test_work_plan_units_reassemble_full_search() {
+    // 日付境界をまたぐ45秒 × Timer0 2種 × VCount 2種 × キー入力2種 = 8レーン
+    let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x1, 8).unwrap();
+    let targets: Vec<u32> = [(0, 0xC79, 0x5F, 0x2FFF), (19, 0xC7A, 0x60, 0x2FFE), (20, 0xC79, 0x60, 0x2FFE), (44, 0xC7A, 0x5F, 0x2FFF)]
+        .iter()
+        .map(|&(offset, timer0, vcount, key_input)| {
+            IntegratedSeedSearcher::try_new(&TEST_MAC, &TEST_NAZO, "DS", key_input, 8).unwrap()
+                .list_seeds(2012, 6, 15, 23, 59, 40, 45, timer0, timer0, vcount, vcount).unwrap()[offset]
+        })
+        .collect();
+    let matcher = TargetMatcher::from_seeds(&targets);
+    let expected = searcher.search_seeds(2012, 6, 15, 23, 59, 40, 45, 0xC79, 0xC7A, 0x5F, 0x60, &targets).unwrap();
+    assert_eq!(expected.len(), 4);
+
+    let summary = |results: &[SearchResult]| {
+        results.iter().map(|r| (r.date(), r.second(), r.timer0(), r.vcount(), r.key_input(), r.seed())).collect::<Vec<_>>()
+    };
+    // レーンをまとめる場合（3ユニット）と、レーンを秒方向に分割する場合（20ユニット → 8レーン × 3）
+    for (requested, expected_units) in [(3, 3), (8, 8), (20, 24), (1000, 8 * 7)] {
+        let plan = WorkPlan::try_new(&searcher, 2012, 6, 15, 23, 59, 40, 45, 0xC79, 0xC7A, 0x5F, 0x60, requested).unwrap();
+        assert_eq!(plan.unit_count(), expected_units, "{requested}");
+
+        let units: Vec<_> = (0..plan.unit_count()).map(|id| plan.unit(id).unwrap()).collect();
+        assert_eq!(units.iter().map(|u| u.candidate_count()).sum::<u64>(), 45 * 8);
+        assert!(units.iter().all(|u| u.range_seconds() > 0 && u.lane_count() > 0));
+        assert!(plan.unit(plan.unit_count()).is_none());
+
+        // ワーカー側では文字列から同じ計画を復元し、IDだけで実行できる
+        let restored = WorkPlan::parse(&plan.serialize()).unwrap();
+        assert_eq!(restored, plan);
+        let mut results = Vec::new();
+        for id in 0..restored.unit_count() {
+            results.extend(restored.try_run_unit(&searcher, id, &matcher).unwrap());
+        }
+        assert_eq!(summary(&results), summary(&expected), "{requested}");
+    }
+
+    // 秒方向の分割はバッチ境界に揃い、コストが均等になる
+    let plan = WorkPlan::try_new(&searcher, 2012, 6, 15, 0, 0, 0, 86400 * 3, 0xC79, 0xC79, 0x60, 0x60, 4).unwrap();
+    let costs: Vec<u64> = (0..4).map(|id| plan.unit(id).unwrap().estimated_cost()).collect();
+    // 日の途中で分割したユニットだけ中間状態の構築が1回増える
+    // （中間状態の構築コストは2、境界は最大3箇所）
+    assert!(costs.iter().sum::<u64>() - plan.total_cost() <= 3 * 2);
+    assert!(costs.iter().max().unwrap() - costs.iter().min().unwrap() <= 2);
+    assert!((0..4).all(|id| plan.unit(id).unwrap().range_seconds().is_multiple_of(8)));
+
+    // 設定の異なる探索器・存在しないユニット・壊れた文字列
+    let other = default_searcher();
+    assert_eq!(plan.try_run_unit(&other, 0, &matcher).err(), Some(InitSeedError::WorkPlanMismatch));
+    assert_eq!(plan.try_run_unit(&searcher, 4, &matcher).err(), Some(InitSeedError::UnknownWorkUnit(4)));
+    for malformed in ["", "v2:0:0:1:0:0:0:0:1:1", "v1:0:0:1:0:0:0:0:0:1", "v1:0:0:1:1:0:0:0:1:1"] {
+        assert_eq!(WorkPlan::parse(malformed), Err(InitSeedError::InvalidWorkPlan), "{malformed}");
+    }
+}
+
+#[test]
+fn test_work_plan_rejects_totals_beyond_u64() {
+    // Timer0・VCount全域 × キー入力4096種 × 約95年分の秒は候補数がu64に収まらない
+    let searcher = IntegratedSeedSearcher::try_with_key_enumeration(&TEST_MAC, &TEST_NAZO, "DS", 0x0FFF, 8).unwrap();
+    assert_eq!(
+        WorkPlan::try_new(&searcher, 2000, 1, 1, 0, 0, 0, 3_000_000_000, 0, 0xFFFF, 0, 0xFFFF, 16).err(),
+        Some(InitSeedError::TooManySearchUnits),
+    );
+
+    // 同じく溢れる計画文字列（候補数・レーン数）は読み込めない
+    for overflowing in ["v1:0:0:3000000000:0:65535:0:65535:4:1", "v1:0:0:1:0:65535:0:65535:4294967295:1"] {
+        assert_eq!(WorkPlan::parse(overflowing), Err(InitSeedError::InvalidWorkPlan), "{overflowing}");
+    }
+}
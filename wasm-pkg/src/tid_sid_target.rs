@@ -0,0 +1,100 @@
+/// TID/SID探索ターゲット
+/// 「始めから」で決まるトレーナーID（TID）・シークレットID（SID）に対する条件。
+/// 指定した条件をすべて満たす起動条件をヒットとし、色違いにしたいPIDは複数指定するといずれかが色違いになればよい
+use wasm_bindgen::prelude::*;
+use crate::pid_shiny_checker::ShinyChecker;
+
+/// TID/SID探索ターゲット
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TidSidTarget {
+    tid: Option<u16>,
+    sid: Option<u16>,
+    shiny_pids: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl TidSidTarget {
+    /// 条件を持たないターゲットを作成（探索前に条件を1つ以上設定する）
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TidSidTarget {
+        TidSidTarget::default()
+    }
+
+    /// 目的のTIDを設定
+    pub fn set_tid(&mut self, tid: u16) {
+        self.tid = Some(tid);
+    }
+
+    /// 目的のSIDを設定
+    pub fn set_sid(&mut self, sid: u16) {
+        self.sid = Some(sid);
+    }
+
+    /// 色違いにしたいPIDを追加
+    pub fn add_shiny_pid(&mut self, pid: u32) {
+        self.shiny_pids.push(pid);
+    }
+
+    /// 目的のTID（未設定はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn tid(&self) -> Option<u16> {
+        self.tid
+    }
+
+    /// 目的のSID（未設定はundefined）
+    #[wasm_bindgen(getter)]
+    pub fn sid(&self) -> Option<u16> {
+        self.sid
+    }
+
+    /// 色違いにしたいPID一覧
+    #[wasm_bindgen(getter)]
+    pub fn shiny_pids(&self) -> Vec<u32> {
+        self.shiny_pids.clone()
+    }
+}
+
+impl TidSidTarget {
+    /// 条件を1つも持たないか
+    pub fn is_empty(&self) -> bool {
+        self.tid.is_none() && self.sid.is_none() && self.shiny_pids.is_empty()
+    }
+
+    /// TID/SIDの組が条件を満たすか
+    #[inline]
+    pub fn matches(&self, tid: u16, sid: u16) -> bool {
+        self.tid.is_none_or(|target| target == tid)
+            && self.sid.is_none_or(|target| target == sid)
+            && (self.shiny_pids.is_empty() || self.shiny_pids.iter().any(|&pid| ShinyChecker::is_shiny(tid, sid, pid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conditions_are_combined() {
+        let mut target = TidSidTarget::new();
+        assert!(target.is_empty());
+        assert!(target.matches(12345, 54321));
+
+        target.set_tid(12345);
+        assert!(!target.is_empty());
+        assert!(target.matches(12345, 0));
+        assert!(!target.matches(12346, 0));
+
+        // 0x12345678: 0x1234 ^ 0x5678 = 0x444C
+        target.add_shiny_pid(0x12345678);
+        assert!(target.matches(12345, 12345 ^ 0x444C));
+        assert!(target.matches(12345, 12345 ^ 0x444C ^ 7));
+        assert!(!target.matches(12345, 12345 ^ 0x444C ^ 8));
+
+        target.add_shiny_pid(0x00000000);
+        assert!(target.matches(12345, 12345));
+
+        target.set_sid(1);
+        assert!(!target.matches(12345, 12345));
+    }
+}
@@ -1,6 +1,7 @@
 /// 共通ユーティリティ関数
 /// WASM Core Engine全体で使用される汎用的な機能を提供
 use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
 
 /// エンディアン変換ユーティリティ
 #[wasm_bindgen]
@@ -168,10 +169,9 @@ impl NumberUtils {
     /// * `hex_str` - 16進数文字列（0xプレフィックス可）
     /// 
     /// # Returns
-    /// 変換された整数値（エラー時は0）
-    pub fn hex_string_to_u32(hex_str: &str) -> u32 {
-        let cleaned = hex_str.trim_start_matches("0x").trim_start_matches("0X");
-        u32::from_str_radix(cleaned, 16).unwrap_or(0)
+    /// 変換された整数値（16進数でない、または32bitを超える場合は例外）
+    pub fn hex_string_to_u32(hex_str: &str) -> Result<u32, JsValue> {
+        Ok(Self::try_hex_string_to_u32(hex_str)?)
     }
 
     /// 32bit整数を16進数文字列に変換
@@ -251,6 +251,19 @@ impl NumberUtils {
     }
 }
 
+impl NumberUtils {
+    /// 16進数文字列を32bit整数に変換（ネイティブ版）
+    /// 空文字列・16進数以外の文字・32bitを超える値は`InvalidHexString`
+    pub fn try_hex_string_to_u32(hex_str: &str) -> Result<u32, InitSeedError> {
+        // 接頭辞は1つだけ取り除く（"0x0x12"のような重複は不正）
+        let cleaned = hex_str.strip_prefix("0x").or_else(|| hex_str.strip_prefix("0X")).unwrap_or(hex_str);
+        if cleaned.is_empty() || !cleaned.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(InitSeedError::InvalidHexString(hex_str.to_string()));
+        }
+        u32::from_str_radix(cleaned, 16).map_err(|_| InitSeedError::InvalidHexString(hex_str.to_string()))
+    }
+}
+
 /// 配列操作ユーティリティ
 #[wasm_bindgen]
 pub struct ArrayUtils;
@@ -334,14 +347,15 @@ pub struct ValidationUtils;
 #[wasm_bindgen]
 impl ValidationUtils {
     /// TIDの妥当性チェック
+    /// JSの数値はu16引数では黙って切り詰められるため、u32で受け取り16bit範囲を判定する
     /// 
     /// # Arguments
     /// * `tid` - トレーナーID
     /// 
     /// # Returns
-    /// 妥当性
-    pub fn is_valid_tid(_tid: u16) -> bool {
-        true // u16は常に16bit範囲内
+    /// 0～65535の範囲内ならtrue
+    pub fn is_valid_tid(tid: u32) -> bool {
+        tid <= 0xFFFF
     }
 
     /// SIDの妥当性チェック
@@ -350,9 +364,9 @@ impl ValidationUtils {
     /// * `sid` - シークレットID
     /// 
     /// # Returns
-    /// 妥当性
-    pub fn is_valid_sid(_sid: u16) -> bool {
-        true // u16は常に16bit範囲内
+    /// 0～65535の範囲内ならtrue
+    pub fn is_valid_sid(sid: u32) -> bool {
+        sid <= 0xFFFF
     }
 
     /// 性格値の妥当性チェック
@@ -385,7 +399,8 @@ impl ValidationUtils {
     /// # Returns
     /// 妥当性
     pub fn is_valid_hex_string(hex_str: &str) -> bool {
-        let cleaned = hex_str.trim_start_matches("0x").trim_start_matches("0X");
+        // 接頭辞は1つだけ取り除く（"0x0x12"のような重複は不正）
+        let cleaned = hex_str.strip_prefix("0x").or_else(|| hex_str.strip_prefix("0X")).unwrap_or(hex_str);
         cleaned.chars().all(|c| c.is_ascii_hexdigit())
     }
 
@@ -447,8 +462,15 @@ mod tests {
     #[test]
     fn test_number_utils() {
         // 16進数変換
-        assert_eq!(NumberUtils::hex_string_to_u32("0x12345678"), 0x12345678);
-        assert_eq!(NumberUtils::hex_string_to_u32("ABCDEF"), 0xABCDEF);
+        assert_eq!(NumberUtils::try_hex_string_to_u32("0x12345678"), Ok(0x12345678));
+        assert_eq!(NumberUtils::try_hex_string_to_u32("ABCDEF"), Ok(0xABCDEF));
+        for invalid in ["", "0x", "0x0x12", "0X0x12", "xyz", "+ff", "0x123456789"] {
+            assert_eq!(
+                NumberUtils::try_hex_string_to_u32(invalid),
+                Err(InitSeedError::InvalidHexString(invalid.to_string())),
+                "{invalid}"
+            );
+        }
         assert_eq!(NumberUtils::u32_to_hex_string(0x12345678, false), "12345678");
         assert_eq!(NumberUtils::u32_to_hex_string(0x12345678, true), "12345678");
         
@@ -486,6 +508,8 @@ mod tests {
         assert!(ValidationUtils::is_valid_tid(12345));
         assert!(ValidationUtils::is_valid_tid(65535));
         assert!(ValidationUtils::is_valid_sid(54321));
+        assert!(!ValidationUtils::is_valid_tid(65536));
+        assert!(!ValidationUtils::is_valid_sid(u32::MAX));
         
         // 性格検証
         assert!(ValidationUtils::is_valid_nature(0));
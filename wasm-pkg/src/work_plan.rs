@@ -0,0 +1,369 @@
+/// ワークユニット分割
+/// 探索空間を独立に実行できるワークユニットへ分割し、安定したIDで参照できるようにする。
+/// 探索空間はTimer0 → VCount → キー入力の順に並べた「レーン」（`SearchParams`の組）と秒の2次元で、
+/// 各ユニットは連続したレーン × 連続した秒の矩形になる。ユニットをID順に連結した結果は`search_seeds`と同一
+use wasm_bindgen::prelude::*;
+use crate::error::InitSeedError;
+use crate::integrated_search::{IntegratedSeedSearcher, SearchParams, SearchRange, SearchResult, SIMD_BATCH_SIZE};
+use crate::target_matcher::TargetMatcher;
+
+// 推定コストはユニット間の負荷を比べるための相対値で、中間状態版スカラーSHA-1 1回を4とする。
+// 値はbenches/sha1_kernels.rsの計測から導出している（リリースビルド、x86_64 1コア）:
+//   sha1_scalar/prefix        約0.20µs/回 → 4
+//   sha1_simd_x4/prefix       約0.35µs/回（スカラー約1.75回分）→ 7
+//   sha1_scalar/prefix_setup  約0.11µs/回（スカラー約0.55回分）→ 2
+// ワーカーのwasm SIMD128とSSE2は4レーンのため、8秒バッチは4レーンカーネル2回分で見積もる
+/// 1日区間ごとのSHA-1中間状態構築コスト
+const SEGMENT_SETUP_COST: u64 = 2;
+/// 4レーンSIMDカーネル1回のコスト
+const SIMD_KERNEL_CALL_COST: u64 = 7;
+/// SIMDバッチ（8秒）1回のコスト（4レーンカーネル × 2回）
+const SIMD_BATCH_COST: u64 = SIMD_KERNEL_CALL_COST * (SIMD_BATCH_SIZE as u64 / 4);
+/// バッチに満たない端数の秒を個別処理するコスト（1秒あたり）
+const SCALAR_SECOND_COST: u64 = 4;
+
+/// ワークユニット
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkUnit {
+    id: u32,
+    lane_start: u64,
+    lane_end: u64,
+    base_seconds_since_2000: i64,
+    range_seconds: u32,
+    estimated_cost: u64,
+}
+
+#[wasm_bindgen]
+impl WorkUnit {
+    /// ユニットID（0から始まる連番）
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// 担当する最初のレーン番号
+    #[wasm_bindgen(getter)]
+    pub fn lane_start(&self) -> u64 {
+        self.lane_start
+    }
+
+    /// 担当するレーン数
+    #[wasm_bindgen(getter)]
+    pub fn lane_count(&self) -> u64 {
+        self.lane_end - self.lane_start
+    }
+
+    /// 担当する秒範囲の開始（2000年1月1日からの経過秒）
+    #[wasm_bindgen(getter)]
+    pub fn base_seconds_since_2000(&self) -> i64 {
+        self.base_seconds_since_2000
+    }
+
+    /// 担当する秒数
+    #[wasm_bindgen(getter)]
+    pub fn range_seconds(&self) -> u32 {
+        self.range_seconds
+    }
+
+    /// 担当する候補数（レーン数 × 秒数）
+    /// 計画全体の候補数がu64に収まることは計画作成時に検証済み
+    #[wasm_bindgen(getter)]
+    pub fn candidate_count(&self) -> u64 {
+        self.lane_count() * self.range_seconds as u64
+    }
+
+    /// 推定コスト（ユニット間の比較に使う相対値）
+    #[wasm_bindgen(getter)]
+    pub fn estimated_cost(&self) -> u64 {
+        self.estimated_cost
+    }
+}
+
+/// ワークユニット分割計画
+/// 探索器の設定・探索範囲・要求ユニット数から決定的に構築されるため、
+/// 同じ計画（またはそのシリアライズ文字列）を持つワーカーはユニットIDだけで担当範囲を特定できる
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkPlan {
+    fingerprint: u64,
+    range: SearchRange,
+    key_count: u32,
+    requested_units: u32,
+    lane_count: u64,
+    // 1レーンを秒方向に分割する数（1の場合は複数レーンをまとめる）
+    units_per_lane: u32,
+    unit_count: u32,
+    total_cost: u64,
+}
+
+#[wasm_bindgen]
+impl WorkPlan {
+    /// 探索範囲をおよそ`unit_count`個のユニットに分割する計画を作成
+    #[allow(clippy::too_many_arguments)]  // Plan requires the full search range
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        searcher: &IntegratedSeedSearcher,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        unit_count: u32,
+    ) -> Result<WorkPlan, JsValue> {
+        Ok(Self::try_new(
+            searcher, year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max, unit_count,
+        )?)
+    }
+
+    /// ユニット数
+    #[wasm_bindgen(getter)]
+    pub fn unit_count(&self) -> u32 {
+        self.unit_count
+    }
+
+    /// 計画全体の推定コスト
+    #[wasm_bindgen(getter)]
+    pub fn total_cost(&self) -> u64 {
+        self.total_cost
+    }
+
+    /// 計画作成時の探索器設定の指紋
+    #[wasm_bindgen(getter)]
+    pub fn config_fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// IDに対応するユニット（範囲外はundefined）
+    pub fn unit(&self, id: u32) -> Option<WorkUnit> {
+        if id >= self.unit_count {
+            return None;
+        }
+
+        let (lane_start, lane_end, second_start, second_end) = if self.units_per_lane == 1 {
+            let lanes = self.lane_count as u128;
+            let units = self.unit_count as u128;
+            let lane_start = (id as u128 * lanes / units) as u64;
+            let lane_end = ((id as u128 + 1) * lanes / units) as u64;
+            (lane_start, lane_end, 0, self.range.range_seconds)
+        } else {
+            let lane = (id / self.units_per_lane) as u64;
+            let piece = id % self.units_per_lane;
+            (lane, lane + 1, self.piece_boundary(piece), self.piece_boundary(piece + 1))
+        };
+
+        let base_seconds_since_2000 = self.range.base_seconds_since_2000 + second_start as i64;
+        let range_seconds = second_end - second_start;
+        Some(WorkUnit {
+            id,
+            lane_start,
+            lane_end,
+            base_seconds_since_2000,
+            range_seconds,
+            estimated_cost: (lane_end - lane_start) * seconds_cost(base_seconds_since_2000, range_seconds),
+        })
+    }
+
+    /// ユニットを実行
+    pub fn run_unit(&self, searcher: &IntegratedSeedSearcher, id: u32, targets: &TargetMatcher) -> Result<js_sys::Array, JsValue> {
+        Ok(IntegratedSeedSearcher::to_js_array(self.try_run_unit(searcher, id, targets)?))
+    }
+
+    /// 文字列形式へシリアライズ（ワーカーへの受け渡し用）
+    pub fn serialize(&self) -> String {
+        format!(
+            "{}:{:016x}:{}:{}:{}:{}:{}:{}:{}:{}",
+            Self::FORMAT_VERSION,
+            self.fingerprint,
+            self.range.base_seconds_since_2000,
+            self.range.range_seconds,
+            self.range.timer0_min,
+            self.range.timer0_max,
+            self.range.vcount_min,
+            self.range.vcount_max,
+            self.key_count,
+            self.requested_units,
+        )
+    }
+
+    /// 文字列形式からデシリアライズ
+    pub fn deserialize(text: &str) -> Result<WorkPlan, JsValue> {
+        Ok(Self::parse(text)?)
+    }
+}
+
+impl WorkPlan {
+    /// シリアライズ形式のバージョン
+    const FORMAT_VERSION: &'static str = "v1";
+
+    /// 分割計画を作成（ネイティブ版）
+    /// `unit_count`が0の場合は1として扱う。レーン数が要求数より少ない場合は各レーンを秒方向に
+    /// SIMDバッチ単位で等分するため、実際のユニット数は要求数以上（レーン数の倍数）になる。
+    /// 候補数・推定コストの合計がu64に収まらない範囲はエラー
+    #[allow(clippy::too_many_arguments)]  // Plan requires the full search range
+    pub fn try_new(
+        searcher: &IntegratedSeedSearcher,
+        year_start: u32,
+        month_start: u32,
+        date_start: u32,
+        hour_start: u32,
+        minute_start: u32,
+        second_start: u32,
+        range_seconds: u32,
+        timer0_min: u32,
+        timer0_max: u32,
+        vcount_min: u32,
+        vcount_max: u32,
+        unit_count: u32,
+    ) -> Result<WorkPlan, InitSeedError> {
+        let range = SearchRange::from_datetime(
+            year_start, month_start, date_start, hour_start, minute_start, second_start,
+            range_seconds, timer0_min, timer0_max, vcount_min, vcount_max,
+        )?;
+        Self::from_parts(searcher.config_fingerprint(), range, searcher.key_inputs().len() as u32, unit_count)
+            .ok_or(InitSeedError::TooManySearchUnits)
+    }
+
+    /// 文字列形式からデシリアライズ（ネイティブ版）
+    pub fn parse(text: &str) -> Result<WorkPlan, InitSeedError> {
+        let fields: Vec<&str> = text.trim().split(':').collect();
+        if fields.len() != 10 || fields[0] != Self::FORMAT_VERSION {
+            return Err(InitSeedError::InvalidWorkPlan);
+        }
+        let number = |index: usize| fields[index].parse::<u32>().map_err(|_| InitSeedError::InvalidWorkPlan);
+
+        let range = SearchRange {
+            base_seconds_since_2000: fields[2].parse().map_err(|_| InitSeedError::InvalidWorkPlan)?,
+            range_seconds: number(3)?,
+            timer0_min: number(4)?,
+            timer0_max: number(5)?,
+            vcount_min: number(6)?,
+            vcount_max: number(7)?,
+        };
+        range.validate().map_err(|_| InitSeedError::InvalidWorkPlan)?;
+        let key_count = number(8)?;
+        if key_count == 0 {
+            return Err(InitSeedError::InvalidWorkPlan);
+        }
+
+        let fingerprint = u64::from_str_radix(fields[1], 16).map_err(|_| InitSeedError::InvalidWorkPlan)?;
+        Self::from_parts(fingerprint, range, key_count, number(9)?).ok_or(InitSeedError::InvalidWorkPlan)
+    }
+
+    /// ユニットを実行（ネイティブ版）
+    /// 探索器は計画作成時と同じ設定である必要がある
+    pub fn try_run_unit(&self, searcher: &IntegratedSeedSearcher, id: u32, targets: &TargetMatcher) -> Result<Vec<SearchResult>, InitSeedError> {
+        if searcher.config_fingerprint() != self.fingerprint {
+            return Err(InitSeedError::WorkPlanMismatch);
+        }
+        let unit = self.unit(id).ok_or(InitSeedError::UnknownWorkUnit(id))?;
+
+        let key_inputs = searcher.key_inputs();
+        let vcount_span = (self.range.vcount_max - self.range.vcount_min + 1) as u64;
+        let mut results = Vec::new();
+        for lane in unit.lane_start..unit.lane_end {
+            let params = SearchParams {
+                timer0: self.range.timer0_min + (lane / key_inputs.len() as u64 / vcount_span) as u32,
+                vcount: self.range.vcount_min + (lane / key_inputs.len() as u64 % vcount_span) as u32,
+                key_input: key_inputs[(lane % key_inputs.len() as u64) as usize],
+            };
+            for (segment_start, segment_seconds) in IntegratedSeedSearcher::day_segments(unit.base_seconds_since_2000, unit.range_seconds) {
+                searcher.process_segment_simd(&params, segment_start, segment_seconds, &mut |params, seconds_since_2000, hash_values| {
+                    searcher.check_and_add_result(hash_values, seconds_since_2000, params, targets, &mut results);
+                });
+            }
+        }
+        Ok(results)
+    }
+
+    /// 計画を組み立てる（レーン数・候補数・推定コストのいずれかがu64に収まらない場合はNone）
+    /// 各ユニットの値は計画全体の値以下になるため、ここで検証すればユニット側は溢れない
+    fn from_parts(fingerprint: u64, range: SearchRange, key_count: u32, requested_units: u32) -> Option<WorkPlan> {
+        let lane_count = [
+            (range.timer0_max - range.timer0_min) as u64 + 1,
+            (range.vcount_max - range.vcount_min) as u64 + 1,
+            key_count as u64,
+        ]
+        .into_iter()
+        .try_fold(1u64, |total, count| total.checked_mul(count))?;
+        lane_count.checked_mul(range.range_seconds as u64)?;
+        let total_cost = lane_count.checked_mul(seconds_cost(range.base_seconds_since_2000, range.range_seconds))?;
+        let requested = requested_units.max(1) as u64;
+
+        let (units_per_lane, unit_count) = if lane_count >= requested {
+            (1, requested as u32)
+        } else {
+            // 1ユニットは少なくとも1バッチを担当し、ユニット数はu32に収める
+            let batches = batch_count(range.base_seconds_since_2000, range.range_seconds).max(1);
+            let per_lane = requested.div_ceil(lane_count).min(batches).min(u32::MAX as u64 / lane_count);
+            (per_lane as u32, u32::try_from(lane_count.checked_mul(per_lane)?).ok()?)
+        };
+
+        Some(WorkPlan { fingerprint, range, key_count, requested_units, lane_count, units_per_lane, unit_count, total_cost })
+    }
+
+    /// `piece`番目の秒方向の分割境界（範囲先頭からの秒オフセット）
+    /// バッチ数を等分し、探索カーネルと同じバッチ境界に揃える
+    fn piece_boundary(&self, piece: u32) -> u32 {
+        let batches = batch_count(self.range.base_seconds_since_2000, self.range.range_seconds);
+        let batch = batches * piece as u64 / self.units_per_lane as u64;
+        batch_offset(self.range.base_seconds_since_2000, self.range.range_seconds, batch)
+    }
+}
+
+/// 秒範囲を処理するSIMDバッチ数（日付境界ごとに端数のバッチが生じる）
+fn batch_count(base_seconds_since_2000: i64, range_seconds: u32) -> u64 {
+    IntegratedSeedSearcher::day_segments(base_seconds_since_2000, range_seconds)
+        .map(|(_, seconds)| seconds.div_ceil(SIMD_BATCH_SIZE) as u64)
+        .sum()
+}
+
+/// `batch`番目のバッチの開始位置（範囲先頭からの秒オフセット、末尾を超える場合は範囲の秒数）
+fn batch_offset(base_seconds_since_2000: i64, range_seconds: u32, batch: u64) -> u32 {
+    let mut remaining = batch;
+    for (segment_start, seconds) in IntegratedSeedSearcher::day_segments(base_seconds_since_2000, range_seconds) {
+        let batches = seconds.div_ceil(SIMD_BATCH_SIZE) as u64;
+        if remaining < batches {
+            return (segment_start - base_seconds_since_2000) as u32 + remaining as u32 * SIMD_BATCH_SIZE;
+        }
+        remaining -= batches;
+    }
+    range_seconds
+}
+
+/// 1レーンで秒範囲を処理する推定コスト
+fn seconds_cost(base_seconds_since_2000: i64, range_seconds: u32) -> u64 {
+    IntegratedSeedSearcher::day_segments(base_seconds_since_2000, range_seconds)
+        .map(|(_, seconds)| {
+            SEGMENT_SETUP_COST
+                + (seconds / SIMD_BATCH_SIZE) as u64 * SIMD_BATCH_COST
+                + (seconds % SIMD_BATCH_SIZE) as u64 * SCALAR_SECOND_COST
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batches_follow_day_boundaries() {
+        // 日付境界の6秒前から13秒: 6秒（端数1バッチ）+ 7秒（端数1バッチ）
+        assert_eq!(batch_count(86400 - 6, 13), 2);
+        assert_eq!(batch_offset(86400 - 6, 13, 1), 6);
+        assert_eq!(batch_offset(86400 - 6, 13, 2), 13);
+        assert_eq!(seconds_cost(86400 - 6, 13), 2 * SEGMENT_SETUP_COST + 13 * SCALAR_SECOND_COST);
+        // 8秒バッチは4レーンカーネル2回分、端数7秒はスカラー7回分
+        assert_eq!(seconds_cost(0, 15), SEGMENT_SETUP_COST + 2 * SIMD_KERNEL_CALL_COST + 7 * SCALAR_SECOND_COST);
+
+        assert_eq!(batch_count(0, 86400 * 2 + 5), 2 * 10800 + 1);
+        assert_eq!(batch_offset(0, 86400 * 2 + 5, 10801), 86408);
+    }
+}